@@ -1,13 +1,62 @@
 use std::env;
+use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+#[path = "build/messages.rs"]
+mod messages;
+#[path = "build/sandesh_idl.rs"]
+mod sandesh_idl;
+
 fn main() {
-    Command::new("make")
-        .output()
-        .expect("Failed to build sandesh modules");
     let dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    generate_sandesh(&dir);
+    // The native codec needs none of the generated C sources
+    if env::var_os("CARGO_FEATURE_NATIVE_CODEC").is_some() {
+        return;
+    }
+    let make = Command::new("make").output().expect("Failed to run make");
+    if !make.status.success() {
+        panic!(
+            "Failed to build sandesh modules, make {}:\n{}",
+            make.status,
+            String::from_utf8_lossy(&make.stderr)
+        );
+    }
     let search_path = Path::new(&dir).join("gen-c/");
     println!("cargo:rustc-link-search=native={}", search_path.display());
     println!("cargo:rustc-link-lib=static=vr_types");
 }
+
+// The message structs of the sandesh objects of priv/vr.sandesh, included by
+// their vr_messages modules, with `Message` and `MessageType`, see
+// build/messages.rs, the C structs of the same objects, see
+// vr_messages::vr_types_binding, and their field layouts, see
+// vr_messages::vr_types_fields
+fn generate_sandesh(dir: &str) {
+    let idl_path = Path::new(dir).join("priv/vr.sandesh");
+    let src = fs::read_to_string(&idl_path).expect("Failed to read priv/vr.sandesh");
+    let idl = sandesh_idl::Idl::parse(&src)
+        .unwrap_or_else(|e| panic!("{}: {}", idl_path.display(), e));
+    let generated = messages::generate(&idl)
+        .unwrap_or_else(|e| panic!("{}: {}", idl_path.display(), e));
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for (file, src) in &generated.modules {
+        let out_path = Path::new(&out_dir).join(file);
+        fs::write(out_path, src).unwrap_or_else(|_| panic!("Failed to write {}", file));
+    }
+    let out_path = Path::new(&out_dir).join("messages.rs");
+    fs::write(out_path, generated.messages).expect("Failed to write messages.rs");
+    let out_path = Path::new(&out_dir).join("message_types.rs");
+    fs::write(out_path, generated.message_types)
+        .expect("Failed to write message_types.rs");
+    let out_path = Path::new(&out_dir).join("vr_sandesh.rs");
+    let src = idl.generate() + &generated.idl;
+    fs::write(out_path, src).expect("Failed to write vr_sandesh.rs");
+    let out_path = Path::new(&out_dir).join("vr_types_structs.rs");
+    fs::write(out_path, idl.generate_bindings())
+        .expect("Failed to write vr_types_structs.rs");
+    let out_path = Path::new(&out_dir).join("vr_types_fields.rs");
+    fs::write(out_path, idl.generate_fields())
+        .expect("Failed to write vr_types_fields.rs");
+}
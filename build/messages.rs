@@ -0,0 +1,774 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// The generator of the `vr_messages` structs of the `buffer sandesh` objects
+// of priv/vr.sandesh, with the `Message` and `MessageType` enums over them.
+//
+// A field of a struct is named after its IDL field, without the prefix its
+// object shares, e.g. `vxlanr_`, and holds it as the IDL types it: its
+// sandesh_op or flow_op becomes `op`, `type` becomes `_type`, lists become
+// a Vec and strings a String. `MESSAGES` lists where a struct differs from
+// that; an object it does not list gets a struct in vr_messages::idl, named
+// after the object.
+
+use super::sandesh_idl::{FieldType, Idl, Sandesh};
+use std::fmt::Write;
+
+/// How the struct of a sandesh object differs from its IDL
+pub struct Message {
+    pub sandesh: &'static str,
+    /// The module of vr_messages the struct is generated in
+    pub module: &'static str,
+    pub name: &'static str,
+    pub fields: &'static [Field],
+    /// Defaults other than `Default::default()`, by field
+    pub defaults: &'static [(&'static str, &'static str)],
+    pub docs: &'static [(&'static str, &'static str)],
+}
+
+pub enum Field {
+    /// An IDL field under another name
+    Rename(&'static str, &'static str),
+    /// An IDL field as another type, converted with `From` when a scalar
+    /// and with `buffers::ListField` when a list
+    Typed(&'static str, &'static str),
+    /// IDL fields held by one field of the given name and type, encoded by
+    /// `write_<name>(&self, &mut Encoder)` and decoded by
+    /// `read_<name>(&sandesh) -> type`, both written by hand in the module
+    Custom(&'static [&'static str], &'static str, &'static str),
+}
+
+use self::Field::{Custom, Rename, Typed};
+
+pub const MESSAGES: &[Message] = &[
+    Message {
+        sandesh: "vr_nexthop_req",
+        module: "vr_nexthop",
+        name: "NexthopRequest",
+        fields: &[
+            Typed("nhr_type", "NhType"),
+            Custom(&["nhr_encap_len"], "encap_len", "usize"),
+            Typed("nhr_tun_sip", "Ipv4Addr"),
+            Typed("nhr_tun_dip", "Ipv4Addr"),
+            Typed("nhr_tun_sip6", "Ipv6Addr"),
+            Typed("nhr_tun_dip6", "Ipv6Addr"),
+            Typed("nhr_pbb_mac", "MacAddress"),
+            Typed("nhr_rw_dst_mac", "MacAddress"),
+        ],
+        defaults: &[
+            ("_type", "NhType::Dead"),
+            ("tun_sip", "Ipv4Addr::UNSPECIFIED"),
+            ("tun_dip", "Ipv4Addr::UNSPECIFIED"),
+            ("tun_sip6", "Ipv6Addr::UNSPECIFIED"),
+            ("tun_dip6", "Ipv6Addr::UNSPECIFIED"),
+        ],
+        docs: &[("family", "One of AF_*")],
+    },
+    Message {
+        sandesh: "vr_interface_req",
+        module: "vr_interface",
+        name: "InterfaceRequest",
+        fields: &[
+            Typed("vifr_type", "IfType"),
+            Typed("vifr_mac", "MacAddress"),
+            Typed("vifr_ip", "Ipv4Addr"),
+            Custom(&["vifr_ip6_u", "vifr_ip6_l"], "ip6", "Ipv6Addr"),
+            Rename("vifr_mir_id", "mirror_id"),
+            Typed("vifr_src_mac", "MacAddress"),
+            Typed("vifr_pbb_mac", "MacAddress"),
+            Typed("vifr_fat_flow_exclude_ip_list", "Vec<Ipv4Addr>"),
+            Custom(
+                &["vifr_fat_flow_exclude_ip6_u_list", "vifr_fat_flow_exclude_ip6_l_list"],
+                "fat_flow_exclude_ip6_list",
+                "Vec<Ipv6Addr>",
+            ),
+            Custom(
+                &["vifr_fat_flow_src_prefix_h", "vifr_fat_flow_src_prefix_l"],
+                "fat_flow_src_prefix",
+                "Vec<u128>",
+            ),
+            Custom(
+                &["vifr_fat_flow_dst_prefix_h", "vifr_fat_flow_dst_prefix_l"],
+                "fat_flow_dst_prefix",
+                "Vec<u128>",
+            ),
+            Typed("vifr_fab_name", "String"),
+            Typed("vifr_fab_drv_name", "String"),
+            Typed("vifr_bond_slave_name", "String"),
+            Typed("vifr_bond_slave_drv_name", "String"),
+            Typed("vifr_vlan_name", "String"),
+        ],
+        defaults: &[
+            ("ip", "Ipv4Addr::UNSPECIFIED"),
+            ("ip6", "Ipv6Addr::UNSPECIFIED"),
+        ],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_vxlan_req",
+        module: "vr_vxlan",
+        name: "VxlanRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_route_req",
+        module: "vr_route",
+        name: "RouteRequest",
+        fields: &[
+            Custom(&["rtr_prefix"], "prefix", "Option<IpAddr>"),
+            Custom(&["rtr_marker"], "marker", "Option<IpAddr>"),
+            Rename("rtr_marker_plen", "marker_prefix_len"),
+            Custom(&["rtr_mac"], "mac", "MacAddress"),
+            Rename("rtr_replace_plen", "replace_prefix_len"),
+        ],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_mpls_req",
+        module: "vr_mpls",
+        name: "MplsRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_mirror_req",
+        module: "vr_mirror",
+        name: "MirrorRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_vrf_req",
+        module: "vr_vrf",
+        name: "VrfRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[
+            ("hbfl_vif_idx", "Host Based Firewall left virtual ifindex"),
+            ("hbfr_vif_idx", "Host Based Firewall right virtual ifindex"),
+        ],
+    },
+    Message {
+        sandesh: "vr_flow_req",
+        module: "vr_flow",
+        name: "FlowRequest",
+        fields: &[
+            Typed("fr_action", "FlowAction"),
+            Custom(&["fr_flow_sip_u", "fr_flow_sip_l"], "flow_sip", "Option<IpAddr>"),
+            Custom(&["fr_flow_dip_u", "fr_flow_dip_l"], "flow_dip", "Option<IpAddr>"),
+            Rename("fr_mir_id", "mirror_id"),
+            Rename("fr_sec_mir_id", "sec_mirror_id"),
+            Custom(&["fr_mir_sip"], "mirror_sip", "IpAddr"),
+            Rename("fr_mir_sport", "mirror_sport"),
+            Typed("fr_pcap_meta_data", "Vec<u8>"),
+            Rename("fr_mir_vrf", "mirror_vrf"),
+            Typed("fr_drop_reason", "FlowDropReason"),
+            Custom(
+                &["fr_rflow_sip_u", "fr_rflow_sip_l"],
+                "reverse_flow_sip",
+                "Option<IpAddr>",
+            ),
+            Custom(
+                &["fr_rflow_dip_u", "fr_rflow_dip_l"],
+                "reverse_flow_dip",
+                "Option<IpAddr>",
+            ),
+            Rename("fr_rflow_nh_id", "reverse_flow_nh_id"),
+            Rename("fr_rflow_sport", "reverse_flow_sport"),
+            Rename("fr_rflow_dport", "reverse_flow_dport"),
+        ],
+        defaults: &[
+            ("rindex", "-1"),
+            ("mirror_sip", "IpAddr::V4(Ipv4Addr::UNSPECIFIED)"),
+        ],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_vrf_assign_req",
+        module: "vr_vrf_assign",
+        name: "VrfAssignRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_vrf_stats_req",
+        module: "vr_vrf_stats",
+        name: "VrfStatsRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_response",
+        module: "vr_response",
+        name: "VrResponse",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vrouter_ops",
+        module: "vrouter_ops",
+        name: "VrouterOps",
+        fields: &[
+            Rename("vo_oflow_bridge_entries", "overflow_flow_bridge_entries"),
+            Rename("vo_oflow_entries", "overflow_flow_entries"),
+            Rename("vo_flow_used_oentries", "flow_used_overflow_entries"),
+            Rename("vo_bridge_used_oentries", "bridge_used_overflow_entries"),
+            Rename("vo_vif_oflow_bridge_entries", "vif_overflow_flow_bridge_entries"),
+        ],
+        defaults: &[],
+        docs: &[
+            ("mpls_labels", "MPLS Lables limit"),
+            ("nexthops", "NextHops limit"),
+            ("bridge_entries", "Bridge Table limit"),
+            ("overflow_flow_bridge_entries", "Bridge Table Overflow limit"),
+            ("flow_entries", "Flow table limit"),
+            ("overflow_flow_entries", "Flow table overflow limit"),
+            ("interfaces", "Interface limit"),
+            ("mirror_entries", "Mirror entries limit"),
+            ("vrfs", "VRF tables limit"),
+            ("perfr", "Performance tweaks: GRO. Known as perfr"),
+            ("perfs", "Performance tweaks: GSO. Known as perfs"),
+            ("from_vm_mss_adj", "TCP MSS on packets from VM"),
+            ("to_vm_mss_adj", "TCP MSS on packets sent to VM"),
+            ("perfr1", "RPS after pulling inner hdr (perfr1)"),
+            ("perfr2", "RPS after GRO on pkt1 (perfr2)"),
+            ("perfr3", "RPS from phys rx handler (perfr3)"),
+            ("perfp", "Pull inner header (faster version)"),
+            ("perfq1", "CPU to send pkts to, if perfr1 set."),
+            ("perfq2", "CPU to send pkts to, if perfr2 set."),
+            ("perfq3", "CPU to send pkts to, if perfr3 set."),
+            ("udp_coff", "NIC cksum offload for outer UDP hdr"),
+            ("flow_hold_limit", "Flow hold limit"),
+            ("mudp", "MPLS over UDP globally"),
+            ("flow_used_entries", "Used Flow entries"),
+            ("flow_used_overflow_entries", "Used Over Flow entries"),
+            ("bridge_used_entries", "Used Bridge entries"),
+            ("bridge_used_overflow_entries", "Used Over Flow bridge entries"),
+            ("burst_tokens", "Burst Total Tokens"),
+            ("burst_interval", "Burst Interval"),
+            ("burst_step", "Burst Step"),
+            ("memory_alloc_checks", "Memory allocation checks"),
+            ("priority_tagging", "NIC Priority Tagging"),
+            ("vif_bridge_entries", "Vif Bridge Table limit"),
+            ("vif_overflow_flow_bridge_entries", "Vif Bridge table Overflow limit"),
+            ("packet_dump", "Packet dump"),
+            ("pkt_droplog_bufsz", "Vrouter pkt drop log buf size"),
+            ("pkt_droplog_buf_en", "Enable/Disable pkt drop debug log infra"),
+            ("pkt_droplog_en", "Vrouter packet drop log enable"),
+            ("pkt_droplog_min_en", "Vrouter Packet drop log minimum enable"),
+            ("close_flow_on_tcp_rst", "Close flow on TCP rst"),
+        ],
+    },
+    Message {
+        sandesh: "vr_mem_stats_req",
+        module: "vr_mem_stats",
+        name: "MemStatsRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_info_req",
+        module: "vr_info",
+        name: "InfoRequest",
+        fields: &[Typed("vdu_msginfo", "InfoMsg")],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_pkt_drop_log_req",
+        module: "vr_pkt_droplog",
+        name: "PktDropLog",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_drop_stats_req",
+        module: "vr_drop_stats",
+        name: "DropStats",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_qos_map_req",
+        module: "vr_qos_map",
+        name: "QosMapRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_fc_map_req",
+        module: "vr_fc_map",
+        name: "FcMapRequest",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_flow_response",
+        module: "vr_flow_response",
+        name: "FlowResponse",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_flow_table_data",
+        module: "vr_flow_table_data",
+        name: "FlowTableData",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_bridge_table_data",
+        module: "vr_bridge_table_data",
+        name: "BridgeTableData",
+        fields: &[],
+        defaults: &[],
+        docs: &[],
+    },
+    Message {
+        sandesh: "vr_hugepage_config",
+        module: "vr_hugepage_config",
+        name: "HugepageConfig",
+        fields: &[
+            Rename("vhp_mem_sz", "mem_size"),
+            Rename("vhp_file_path_sz", "file_path_size"),
+        ],
+        defaults: &[],
+        docs: &[],
+    },
+];
+
+/// The generated sources, by the file name they are included as
+pub struct Generated {
+    /// The struct of each object with a module, in `<object>.rs`
+    pub modules: Vec<(String, String)>,
+    /// The structs of the other objects, for vr_messages::idl
+    pub idl: String,
+    /// `Message`, for vr_messages
+    pub messages: String,
+    /// `MessageType`, for vr_messages::message_type
+    pub message_types: String,
+}
+
+pub fn generate(idl: &Idl) -> Result<Generated, String> {
+    let mut generated = Generated {
+        modules: Vec::new(),
+        idl: String::new(),
+        messages: String::new(),
+        message_types: String::new(),
+    };
+    if let Some(message) = MESSAGES
+        .iter()
+        .find(|m| !idl.sandeshes.iter().any(|s| s.name == m.sandesh))
+    {
+        return Err(format!("{} is not in the IDL", message.sandesh));
+    }
+    let layouts = idl
+        .sandeshes
+        .iter()
+        .map(Layout::new)
+        .collect::<Result<Vec<_>, _>>()?;
+    for layout in &layouts {
+        let mut out = String::new();
+        layout.generate(&mut out);
+        match layout.module {
+            Some(_) => {
+                let file = format!("{}.rs", layout.sandesh.name);
+                generated.modules.push((file, out));
+            }
+            None => generated.idl.push_str(&out),
+        }
+    }
+    generate_messages(&layouts, &mut generated.messages);
+    generate_message_types(&layouts, &mut generated.message_types);
+    Ok(generated)
+}
+
+// private functions
+
+/// The struct of a sandesh object
+struct Layout<'a> {
+    sandesh: &'a Sandesh,
+    name: String,
+    module: Option<&'static str>,
+    /// The Rust type of `op`, and whether it is a FlowOp
+    op: MessageField,
+    flow_op: bool,
+    fields: Vec<MessageField>,
+}
+
+struct MessageField {
+    name: String,
+    ty: String,
+    codec: Codec,
+    doc: Option<&'static str>,
+    default: Option<&'static str>,
+}
+
+enum Codec {
+    /// Copied as is
+    Copy(String),
+    /// Converted with `From` both ways
+    Into(String),
+    String(String),
+    /// Converted with `ListField`, from lists of the element type
+    List(String, &'static str),
+    /// Encoded and decoded by the hooks of the module
+    Custom,
+}
+
+impl<'a> Layout<'a> {
+    fn new(sandesh: &'a Sandesh) -> Result<Layout<'a>, String> {
+        let message = MESSAGES.iter().find(|m| m.sandesh == sandesh.name);
+        let overlay: &[Field] = message.map_or(&[], |m| m.fields);
+        let op_field = sandesh
+            .fields
+            .iter()
+            .find(|f| op_type(&f.ty).is_some())
+            .ok_or_else(|| format!("{} has no sandesh_op or flow_op", sandesh.name))?;
+        let prefix = sandesh
+            .fields
+            .iter()
+            .find(|f| f.name != op_field.name)
+            .and_then(|f| f.name.find('_').map(|end| &f.name[..=end]))
+            .unwrap_or("");
+        let flow_op = op_field.ty == FieldType::Enum("flow_op".to_string());
+        let op = MessageField {
+            name: "op".to_string(),
+            ty: op_type(&op_field.ty).unwrap().to_string(),
+            codec: Codec::Into(op_field.name.clone()),
+            doc: None,
+            default: None,
+        };
+
+        let mut fields = Vec::new();
+        for field in sandesh.fields.iter().filter(|f| f.name != op_field.name) {
+            let name = field.name.trim_start_matches(prefix);
+            let name = match name {
+                "type" => "_type".to_string(),
+                name => name.to_string(),
+            };
+            let custom = overlay.iter().find_map(|f| match f {
+                Custom(covered, name, ty) if covered.contains(&field.name.as_str()) => {
+                    Some((covered[0] == field.name, *name, *ty))
+                }
+                _ => None,
+            });
+            let field = match custom {
+                // Held by the field of the first IDL field it covers
+                Some((false, _, _)) => continue,
+                Some((true, name, ty)) => MessageField {
+                    name: name.to_string(),
+                    ty: ty.to_string(),
+                    codec: Codec::Custom,
+                    doc: None,
+                    default: None,
+                },
+                None => {
+                    let name = overlay
+                        .iter()
+                        .find_map(|f| match f {
+                            Rename(idl_name, name) if *idl_name == field.name => {
+                                Some(name.to_string())
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(name);
+                    let typed = overlay.iter().find_map(|f| match f {
+                        Typed(idl_name, ty) if *idl_name == field.name => Some(*ty),
+                        _ => None,
+                    });
+                    let idl_name = field.name.clone();
+                    let (ty, codec) = match (&field.ty, typed) {
+                        (FieldType::String, _) => {
+                            ("String".to_string(), Codec::String(idl_name))
+                        }
+                        (FieldType::List(base), ty) => {
+                            let elem = base.rust_type();
+                            let ty = ty.map_or(format!("Vec<{}>", elem), String::from);
+                            (ty, Codec::List(idl_name, elem))
+                        }
+                        (_, Some(typed)) => (typed.to_string(), Codec::Into(idl_name)),
+                        (FieldType::Base(base), None) => {
+                            (base.rust_type().to_string(), Codec::Copy(idl_name))
+                        }
+                        (ty, None) => match op_type(ty) {
+                            Some(op) => (op.to_string(), Codec::Into(idl_name)),
+                            // The other enums of the IDL are left as they are
+                            None => ("u32".to_string(), Codec::Copy(idl_name)),
+                        },
+                    };
+                    MessageField {
+                        name,
+                        ty,
+                        codec,
+                        doc: None,
+                        default: None,
+                    }
+                }
+            };
+            fields.push(field);
+        }
+        for field in &mut fields {
+            if let Some(message) = message {
+                field.doc = lookup(message.docs, &field.name);
+                field.default = lookup(message.defaults, &field.name);
+            }
+        }
+        for name in overlay.iter().map(|f| match f {
+            Rename(idl_name, _) | Typed(idl_name, _) => *idl_name,
+            Custom(covered, _, _) => covered[0],
+        }) {
+            if !sandesh.fields.iter().any(|f| f.name == name) {
+                return Err(format!("{} has no field {}", sandesh.name, name));
+            }
+        }
+
+        Ok(Layout {
+            sandesh,
+            name: message.map_or_else(|| sandesh.type_name(), |m| m.name.to_string()),
+            module: message.map(|m| m.module),
+            op,
+            flow_op,
+            fields,
+        })
+    }
+
+    /// The path of the struct from vr_messages
+    fn path(&self) -> String {
+        format!("{}::{}", self.module.unwrap_or("idl"), self.name)
+    }
+
+    fn generate(&self, out: &mut String) {
+        let name = &self.name;
+        let sandesh =
+            format!("crate::vr_messages::vr_types_binding::{}", self.sandesh.name);
+        let codec_error = "crate::vr_messages::CodecError";
+        let derived = self.fields.iter().all(|f| f.default.is_none());
+
+        writeln!(out, "/// A `{}` of priv/vr.sandesh.", self.sandesh.name).unwrap();
+        match derived {
+            true => out.push_str("#[derive(Debug, Default, Clone, Eq, PartialEq)]\n"),
+            false => out.push_str("#[derive(Debug, Clone, Eq, PartialEq)]\n"),
+        }
+        writeln!(out, "pub struct {} {{", name).unwrap();
+        writeln!(out, "    pub op: {},", self.op.ty).unwrap();
+        out.push_str("    pub read_length: usize,\n");
+        for field in &self.fields {
+            if let Some(doc) = field.doc {
+                writeln!(out, "    /// {}", doc).unwrap();
+            }
+            writeln!(out, "    pub {}: {},", field.name, field.ty).unwrap();
+        }
+        out.push_str("}\n\n");
+
+        if !derived {
+            writeln!(out, "impl Default for {} {{", name).unwrap();
+            writeln!(out, "    fn default() -> {} {{", name).unwrap();
+            writeln!(out, "        {} {{", name).unwrap();
+            out.push_str("            op: Default::default(),\n");
+            out.push_str("            read_length: 0,\n");
+            for field in &self.fields {
+                let default = field.default.unwrap_or("Default::default()");
+                writeln!(out, "            {}: {},", field.name, default).unwrap();
+            }
+            out.push_str("        }\n    }\n}\n\n");
+        }
+
+        writeln!(out, "impl {} {{", name).unwrap();
+        writeln!(
+            out,
+            "    pub fn write(&self) -> Result<Vec<u8>, {}> {{",
+            codec_error
+        )
+        .unwrap();
+        out.push_str("        use crate::vr_messages::buffers::Encoder;\n");
+        out.push_str("        use crate::vr_messages::vr_types::VrSandesh;\n");
+        writeln!(out, "        let mut encoder = Encoder::<{}>::new();", sandesh)
+            .unwrap();
+        for field in Some(&self.op).into_iter().chain(&self.fields) {
+            let value = format!("self.{}", field.name);
+            match &field.codec {
+                Codec::Copy(idl) => writeln!(out, "        encoder.{} = {};", idl, value),
+                Codec::Into(idl) => {
+                    writeln!(out, "        encoder.{} = {}.into();", idl, value)
+                }
+                Codec::String(idl) => writeln!(
+                    out,
+                    "        encoder.{} = encoder.string(&{})?;",
+                    idl, value
+                ),
+                Codec::List(idl, elem) => writeln!(
+                    out,
+                    "        let (list, size) = encoder.list_field::<{}, _>(&{});\n\
+                     \x20       encoder.{} = list;\n\
+                     \x20       encoder.{}_size = size;",
+                    elem, value, idl, idl
+                ),
+                Codec::Custom => {
+                    writeln!(out, "        self.write_{}(&mut encoder);", field.name)
+                }
+            }
+            .unwrap();
+        }
+        out.push_str("        encoder.write()\n    }\n\n");
+
+        writeln!(
+            out,
+            "    pub fn read(buf: Vec<u8>) -> Result<{}, {}> {{",
+            name, codec_error
+        )
+        .unwrap();
+        out.push_str("        Self::from_slice(&buf)\n    }\n\n");
+
+        writeln!(
+            out,
+            "    pub fn from_slice(buf: &[u8]) -> Result<{}, {}> {{",
+            name, codec_error
+        )
+        .unwrap();
+        let buffers = (self.fields.iter())
+            .any(|f| matches!(f.codec, Codec::String(_) | Codec::List(..)));
+        let imports = if buffers { "{self, Decoder}" } else { "Decoder" };
+        writeln!(out, "        use crate::vr_messages::buffers::{};", imports).unwrap();
+        out.push_str("        use crate::vr_messages::vr_types::VrSandesh;\n");
+        writeln!(out, "        let mut decoder = Decoder::<{}>::new();", sandesh)
+            .unwrap();
+        out.push_str("        let rxfer = decoder.read(buf)?;\n");
+        writeln!(out, "        Ok({} {{", name).unwrap();
+        out.push_str("            read_length: rxfer as usize,\n");
+        for field in Some(&self.op).into_iter().chain(&self.fields) {
+            let value = match &field.codec {
+                Codec::Copy(idl) => format!("decoder.{}", idl),
+                Codec::Into(idl) => format!("decoder.{}.into()", idl),
+                Codec::String(idl) => format!("buffers::read_string(decoder.{})", idl),
+                Codec::List(idl, _) => format!(
+                    "buffers::read_list_field(decoder.{}, decoder.{}_size)",
+                    idl, idl
+                ),
+                Codec::Custom => format!("Self::read_{}(&decoder)", field.name),
+            };
+            writeln!(out, "            {}: {},", field.name, value).unwrap();
+        }
+        out.push_str("        })\n    }\n}\n\n");
+    }
+}
+
+fn generate_messages(layouts: &[Layout], out: &mut String) {
+    out.push_str("/// Any sandesh object of priv/vr.sandesh.\n");
+    out.push_str("#[derive(Debug, Clone, Eq, PartialEq)]\n");
+    out.push_str("pub enum Message {\n");
+    for layout in layouts {
+        writeln!(out, "    {}({}),", layout.name, layout.path()).unwrap();
+    }
+    out.push_str("}\n\nimpl Message {\n");
+
+    out.push_str(
+        "    /// Decodes the sandesh object at the start of `buf`, which may be \
+         followed\n    /// by others.\n",
+    );
+    out.push_str("    pub fn from_slice(buf: &[u8]) -> Result<Message, CodecError> {\n");
+    out.push_str("        let message = match MessageType::try_from(buf)? {\n");
+    for layout in layouts {
+        writeln!(
+            out,
+            "            MessageType::{} => Message::{}({}::from_slice(buf)?),",
+            layout.name,
+            layout.name,
+            layout.path()
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "            MessageType::Unknown => return \
+         Err(CodecError::UnknownMessageType),\n",
+    );
+    out.push_str("        };\n        Ok(message)\n    }\n\n");
+
+    let arms = |out: &mut String, sig: &str, arm: &dyn Fn(&Layout) -> String| {
+        writeln!(out, "    {} {{\n        match self {{", sig).unwrap();
+        for layout in layouts {
+            let name = &layout.name;
+            writeln!(out, "            Message::{}{},", name, arm(layout)).unwrap();
+        }
+        out.push_str("        }\n    }\n\n");
+    };
+    arms(
+        out,
+        "pub fn to_bytes(&self) -> Result<Vec<u8>, CodecError>",
+        &|_| "(m) => m.write()".to_string(),
+    );
+    arms(out, "pub fn read_length(&self) -> usize", &|_| {
+        "(m) => m.read_length".to_string()
+    });
+    arms(out, "pub fn message_type(&self) -> MessageType", &|layout| {
+        format!("(_) => MessageType::{}", layout.name)
+    });
+    out.push_str(
+        "    /// The operation the message asks for, see `From<FlowOp>` for that of \
+         flow\n    /// messages.\n",
+    );
+    arms(out, "pub fn sandesh_op(&self) -> SandeshOp", &|layout| {
+        match layout.flow_op {
+            true => "(m) => m.op.into()".to_string(),
+            false => "(m) => m.op".to_string(),
+        }
+    });
+    out.truncate(out.len() - 1);
+    out.push_str("}\n");
+}
+
+fn generate_message_types(layouts: &[Layout], out: &mut String) {
+    out.push_str("/// The type of a `Message`, `Unknown` for objects priv/vr.sandesh ");
+    out.push_str("does not declare.\n");
+    out.push_str("#[derive(Debug, Clone, Eq, PartialEq)]\n");
+    out.push_str("pub enum MessageType {\n");
+    for layout in layouts {
+        writeln!(out, "    {},", layout.name).unwrap();
+    }
+    out.push_str("    Unknown,\n}\n\n");
+    out.push_str("impl From<SandeshType> for MessageType {\n");
+    out.push_str("    fn from(sandesh_type: SandeshType) -> MessageType {\n");
+    out.push_str("        match sandesh_type {\n");
+    for layout in layouts {
+        writeln!(
+            out,
+            "            SandeshType::{} => MessageType::{},",
+            layout.sandesh.type_name(),
+            layout.name
+        )
+        .unwrap();
+    }
+    out.push_str("        }\n    }\n}\n");
+}
+
+// The Rust type of a sandesh_op or flow_op
+fn op_type(ty: &FieldType) -> Option<&'static str> {
+    match ty {
+        FieldType::Enum(name) if name == "sandesh_op" => {
+            Some("crate::vr_messages::sandesh::SandeshOp")
+        }
+        FieldType::Enum(name) if name == "flow_op" => {
+            Some("crate::vr_messages::vr_flow::FlowOp")
+        }
+        _ => None,
+    }
+}
+
+fn lookup(table: &[(&'static str, &'static str)], name: &str) -> Option<&'static str> {
+    table.iter().find(|(field, _)| *field == name).map(|(_, value)| *value)
+}
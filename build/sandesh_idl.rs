@@ -0,0 +1,471 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// A parser for the subset of the sandesh IDL used by priv/vr.sandesh, and the
+// generator of the Rust code for its `buffer sandesh` objects.
+
+use std::fmt::Write;
+
+#[derive(Debug)]
+pub struct Idl {
+    pub enums: Vec<Enum>,
+    pub sandeshes: Vec<Sandesh>,
+}
+
+/// An `enum` declaration, with the value of each enumerator
+#[derive(Debug)]
+pub struct Enum {
+    pub name: String,
+    pub values: Vec<(String, u32)>,
+}
+
+/// A `buffer sandesh` declaration
+#[derive(Debug)]
+pub struct Sandesh {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug)]
+pub struct Field {
+    pub id: i16,
+    pub ty: FieldType,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BaseType {
+    Bool,
+    Byte,
+    I16,
+    I32,
+    I64,
+    U16,
+    U32,
+    U64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FieldType {
+    Base(BaseType),
+    String,
+    List(BaseType),
+    /// An enum declared in the IDL, carried as an i32
+    Enum(String),
+}
+
+impl Idl {
+    pub fn parse(src: &str) -> Result<Idl, String> {
+        let tokens = tokenize(src)?;
+        let mut p = Parser { tokens, pos: 0 };
+        let mut idl = Idl {
+            enums: Vec::new(),
+            sandeshes: Vec::new(),
+        };
+        while let Some(token) = p.next() {
+            match token.as_str() {
+                "enum" => {
+                    let name = p.ident()?;
+                    let values = p.enumerators()?;
+                    idl.enums.push(Enum { name, values });
+                }
+                "struct" => {
+                    p.ident()?;
+                    p.skip_block()?;
+                }
+                "buffer" => {
+                    p.expect("sandesh")?;
+                    let name = p.ident()?;
+                    let fields = p.fields(&idl.enums)?;
+                    idl.sandeshes.push(Sandesh { name, fields });
+                }
+                // request, trace, systemlog... sandeshes never cross netlink
+                _ if p.peek() == Some("sandesh") => {
+                    p.next();
+                    p.ident()?;
+                    p.skip_block()?;
+                }
+                token => return Err(format!("unexpected `{}`", token)),
+            }
+        }
+        Ok(idl)
+    }
+
+    /// `SandeshType`, naming the objects, see vr_messages::idl
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        self.generate_sandesh_type(&mut out);
+        out
+    }
+
+    /// The structs the C codec generates for the same objects, laid out as
+    /// bindgen would, with their enums and functions
+    pub fn generate_bindings(&self) -> String {
+        let mut out = String::new();
+        for e in &self.enums {
+            for (name, value) in &e.values {
+                writeln!(
+                    out,
+                    "pub const _{}_{}_{}: _{} = {};",
+                    e.name,
+                    e.name.to_uppercase(),
+                    name,
+                    e.name,
+                    value
+                )
+                .unwrap();
+            }
+            writeln!(out, "pub type _{} = u32;", e.name).unwrap();
+            writeln!(out, "pub use self::_{} as {};", e.name, e.name).unwrap();
+        }
+        self.sandeshes.iter().for_each(|sandesh| sandesh.generate_binding(&mut out));
+        out
+    }
+
+    /// The `sandesh!` and `vr_sandesh!` layouts of the structs the C codec
+    /// generates for the same objects, see vr_messages::vr_types_fields
+    pub fn generate_fields(&self) -> String {
+        let mut out = String::new();
+        for sandesh in &self.sandeshes {
+            let mut fields = String::new();
+            for field in &sandesh.fields {
+                let declare = field.ty.declare(&field.name);
+                writeln!(fields, "    {}: {};", field.id, declare).unwrap();
+            }
+            writeln!(out, "sandesh!({} {{\n{}}});\n", sandesh.name, fields).unwrap();
+            let name = &sandesh.name;
+            let functions = format!(
+                "{}_write_binary_to_buffer, {}_read_binary_from_buffer",
+                name, name
+            );
+            writeln!(out, "vr_sandesh!({}({}) {{", name, functions).unwrap();
+            writeln!(out, "{}}});\n", fields).unwrap();
+        }
+        out
+    }
+
+    // private functions
+
+    fn generate_sandesh_type(&self, out: &mut String) {
+        out.push_str("/// The sandesh objects of priv/vr.sandesh.\n");
+        out.push_str("#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]\n");
+        out.push_str("pub enum SandeshType {\n");
+        for sandesh in &self.sandeshes {
+            writeln!(out, "    {},", sandesh.type_name()).unwrap();
+        }
+        out.push_str("}\n\nimpl SandeshType {\n");
+        out.push_str("    pub const ALL: &'static [SandeshType] = &[\n");
+        for sandesh in &self.sandeshes {
+            writeln!(out, "        SandeshType::{},", sandesh.type_name()).unwrap();
+        }
+        out.push_str("    ];\n\n");
+        out.push_str("    pub fn from_name(name: &str) -> Option<SandeshType> {\n");
+        out.push_str("        match name {\n");
+        for sandesh in &self.sandeshes {
+            writeln!(
+                out,
+                "            {:?} => Some(SandeshType::{}),",
+                sandesh.name,
+                sandesh.type_name()
+            )
+            .unwrap();
+        }
+        out.push_str("            _ => None,\n        }\n    }\n\n");
+        out.push_str("    pub fn name(self) -> &'static str {\n        match self {\n");
+        for sandesh in &self.sandeshes {
+            writeln!(
+                out,
+                "            SandeshType::{} => {:?},",
+                sandesh.type_name(),
+                sandesh.name
+            )
+            .unwrap();
+        }
+        out.push_str("        }\n    }\n\n");
+        out.push_str("    /// The name of field `id`, as declared in priv/vr.sandesh.\n");
+        out.push_str("    pub fn field_name(self, id: i16) -> Option<&'static str> {\n");
+        out.push_str("        let name = match (self, id) {\n");
+        for sandesh in &self.sandeshes {
+            for field in &sandesh.fields {
+                writeln!(
+                    out,
+                    "            (SandeshType::{}, {}) => {:?},",
+                    sandesh.type_name(),
+                    field.id,
+                    field.name
+                )
+                .unwrap();
+            }
+        }
+        out.push_str("            _ => return None,\n        };\n        Some(name)\n");
+        out.push_str("    }\n}\n\n");
+    }
+}
+
+impl Sandesh {
+    /// vr_nexthop_req is generated as VrNexthopReq
+    pub fn type_name(&self) -> String {
+        self.name
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| word[..1].to_uppercase() + &word[1..])
+            .collect()
+    }
+
+    // private functions
+
+    fn generate_binding(&self, out: &mut String) {
+        out.push_str("#[repr(C)]\n#[derive(Debug, Copy, Clone)]\n");
+        writeln!(out, "pub struct _{} {{", self.name).unwrap();
+        for field in &self.fields {
+            writeln!(out, "    pub {}: {},", field.name, field.ty.c_type()).unwrap();
+            if let FieldType::List(_) = field.ty {
+                writeln!(out, "    pub {}_size: u_int32_t,", field.name).unwrap();
+            }
+            writeln!(out, "    pub __isset_{}: u_int8_t,", field.name).unwrap();
+        }
+        out.push_str("}\n");
+        writeln!(out, "pub type {} = _{};", self.name, self.name).unwrap();
+        let void = "*mut ::std::os::raw::c_void";
+        let error = "error: *mut ::std::os::raw::c_int";
+        let protocol = "protocol: *mut ThriftProtocol";
+        let buf = "buf: *mut u8, buf_len: usize";
+        let functions = [
+            ("write", "wsandesh", protocol),
+            ("write_binary_to_buffer", "wsandesh", buf),
+            ("read", "rsandesh", protocol),
+            ("read_binary_from_buffer", "rsandesh", buf),
+        ];
+        for (function, arg, params) in functions.iter() {
+            writeln!(
+                out,
+                "extern \"C\" {{\n    pub fn {}_{}({}: {}, {}, {}) -> i32;\n}}",
+                self.name, function, arg, void, params, error
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "extern \"C\" {{\n    pub fn {}_free(fsandesh: {});\n}}",
+            self.name, void
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "extern \"C\" {{\n    pub fn {}_process(p{}: {});\n}}",
+            self.name, self.name, void
+        )
+        .unwrap();
+    }
+}
+
+impl BaseType {
+    fn parse(name: &str) -> Option<BaseType> {
+        match name {
+            "bool" => Some(BaseType::Bool),
+            "byte" => Some(BaseType::Byte),
+            "i16" => Some(BaseType::I16),
+            "i32" => Some(BaseType::I32),
+            "i64" => Some(BaseType::I64),
+            "u16" => Some(BaseType::U16),
+            "u32" => Some(BaseType::U32),
+            "u64" => Some(BaseType::U64),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            BaseType::Bool => "bool",
+            BaseType::Byte => "byte",
+            BaseType::I16 => "i16",
+            BaseType::I32 => "i32",
+            BaseType::I64 => "i64",
+            BaseType::U16 => "u16",
+            BaseType::U32 => "u32",
+            BaseType::U64 => "u64",
+        }
+    }
+
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            BaseType::Bool => "bool",
+            BaseType::Byte => "i8",
+            BaseType::I16 => "i16",
+            BaseType::I32 => "i32",
+            BaseType::I64 => "i64",
+            BaseType::U16 => "u16",
+            BaseType::U32 => "u32",
+            BaseType::U64 => "u64",
+        }
+    }
+}
+
+impl FieldType {
+    // As the IDL declares `name`, lists naming the field holding their size
+    fn declare(&self, name: &str) -> String {
+        match self {
+            FieldType::Base(base) => format!("{} {}", base.name(), name),
+            FieldType::String => format!("string {}", name),
+            FieldType::List(base) => {
+                format!("list<{}> {}[{}_size]", base.name(), name, name)
+            }
+            FieldType::Enum(enum_name) => format!("enum<{}> {}", enum_name, name),
+        }
+    }
+
+    // As bindgen declares the C field
+    fn c_type(&self) -> String {
+        match self {
+            FieldType::Base(base) => base.rust_type().to_string(),
+            FieldType::String => "*mut ::std::os::raw::c_char".to_string(),
+            FieldType::List(base) => format!("*mut {}", base.rust_type()),
+            FieldType::Enum(enum_name) => enum_name.clone(),
+        }
+    }
+}
+
+// private functions
+
+struct Parser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&str> { self.tokens.get(self.pos).map(String::as_str) }
+
+    fn token(&mut self) -> Result<String, String> {
+        self.next().ok_or_else(|| "unexpected end of file".to_string())
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.token()? {
+            ref token if token == expected => Ok(()),
+            token => Err(format!("expected `{}`, found `{}`", expected, token)),
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, String> {
+        let token = self.token()?;
+        if token.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            Ok(token)
+        } else {
+            Err(format!("expected an identifier, found `{}`", token))
+        }
+    }
+
+    fn skip_block(&mut self) -> Result<(), String> {
+        self.expect("{")?;
+        while self.token()? != "}" {}
+        Ok(())
+    }
+
+    fn enumerators(&mut self) -> Result<Vec<(String, u32)>, String> {
+        self.expect("{")?;
+        let mut values = Vec::new();
+        let mut value = 0;
+        while self.peek() != Some("}") {
+            let name = self.ident()?;
+            if self.peek() == Some("=") {
+                self.next();
+                let token = self.token()?;
+                value = token
+                    .parse()
+                    .map_err(|_| format!("invalid value `{}` of `{}`", token, name))?;
+            }
+            if self.peek() == Some(",") {
+                self.next();
+            }
+            values.push((name, value));
+            value += 1;
+        }
+        self.next();
+        Ok(values)
+    }
+
+    fn fields(&mut self, enums: &[Enum]) -> Result<Vec<Field>, String> {
+        self.expect("{")?;
+        let mut fields = Vec::new();
+        while self.peek() != Some("}") {
+            let id = self.token()?;
+            let id = id.parse().map_err(|_| format!("invalid field id `{}`", id))?;
+            self.expect(":")?;
+            if let Some("optional") | Some("required") = self.peek() {
+                self.next();
+            }
+            let ty = self.field_type(enums)?;
+            let name = self.ident()?;
+            self.expect(";")?;
+            fields.push(Field { id, ty, name });
+        }
+        self.next();
+        Ok(fields)
+    }
+
+    fn field_type(&mut self, enums: &[Enum]) -> Result<FieldType, String> {
+        let name = self.ident()?;
+        if let Some(base) = BaseType::parse(&name) {
+            return Ok(FieldType::Base(base));
+        }
+        match name.as_str() {
+            "string" => Ok(FieldType::String),
+            "list" => {
+                self.expect("<")?;
+                let elem = self.ident()?;
+                self.expect(">")?;
+                BaseType::parse(&elem)
+                    .map(FieldType::List)
+                    .ok_or_else(|| format!("unsupported list element `{}`", elem))
+            }
+            name if enums.iter().any(|e| e.name == name) => {
+                Ok(FieldType::Enum(name.to_string()))
+            }
+            name => Err(format!("unsupported field type `{}`", name)),
+        }
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = src.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|&c| c == '\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if prev == '*' => break,
+                        Some(c) => prev = c,
+                        None => return Err("unterminated comment".to_string()),
+                    }
+                }
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' => {
+                let mut token = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    token.push(c);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+            '{' | '}' | '<' | '>' | ':' | ';' | ',' | '=' => tokens.push(c.to_string()),
+            c => return Err(format!("unexpected character `{}`", c)),
+        }
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,64 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prints the timeline of vrouter requests and replies in a pcap recorded
+//! on an `nlmon` interface.
+//!
+//! usage: vr_capture_decode <capture.pcap>
+
+extern crate vr_type;
+
+use std::time::{Duration, UNIX_EPOCH};
+use std::{env, fs, process};
+use vr_type::genetlink::{decode_capture, CapturedPayload};
+use vr_type::netlink::pcap::Direction;
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: vr_capture_decode <capture.pcap>");
+            process::exit(2);
+        }
+    };
+    let pcap = match fs::read(&path) {
+        Ok(pcap) => pcap,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        }
+    };
+    let timeline = match decode_capture(&pcap[..]) {
+        Ok(timeline) => timeline,
+        Err(err) => {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        }
+    };
+    for entry in timeline {
+        let since_epoch = entry.timestamp.duration_since(UNIX_EPOCH);
+        let since_epoch = since_epoch.unwrap_or(Duration::from_secs(0));
+        let arrow = match entry.direction {
+            Direction::Outgoing => "->",
+            Direction::Incoming => "<-",
+        };
+        let header = format!(
+            "{}.{:06} {} seq {} pid {}",
+            since_epoch.as_secs(),
+            since_epoch.subsec_micros(),
+            arrow,
+            entry.seq,
+            entry.pid
+        );
+        match entry.payload {
+            CapturedPayload::Sandesh(messages) => {
+                for message in messages {
+                    println!("{} {:?}", header, message);
+                }
+            }
+            CapturedPayload::Ack(ack) if ack.is_ack() => println!("{} ACK", header),
+            CapturedPayload::Ack(ack) => println!("{} ERROR {}", header, ack),
+            CapturedPayload::Invalid(err) => println!("{} INVALID {}", header, err),
+        }
+    }
+}
@@ -0,0 +1,441 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::VROUTER_GENETLINK_FAMILY_NAME;
+use super::transport::sandesh_payloads;
+use super::family::{family_from_reply, not_found};
+use super::observer::notify;
+use super::retry::RetryPolicy;
+use super::{handle_sandesh_reply, MessageHandleError};
+use super::{CTRL_ATTR_FAMILY_NAME, CTRL_CMD_GETFAMILY, GENL_ID_CTRL};
+use super::{NETLINK_VERSION, NL_ATTR_VR_MESSAGE_PROTOCOL, SANDESH_REQUEST};
+use crate::netlink::message::recv_datagram;
+use crate::netlink::pcap::{capture, Direction};
+use crate::netlink::{deserialize_messages, set_ext_ack, NetlinkAttr, NetlinkError};
+use crate::netlink::{in_netns, NetNs};
+use crate::netlink::{nlmsghdr, NLMSG_ALIGN, NLMSG_LENGTH};
+use crate::netlink::{NetlinkMessage, ReplyCollector, Serialize, NLM_F_REQUEST};
+use crate::vr_messages::Message;
+use futures::channel::oneshot;
+use futures::future::{self, poll_fn};
+use futures::ready;
+use mio::Ready;
+use netlink_sys::Protocol::Generic;
+use netlink_sys::Socket;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::io;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tokio::io::PollEvented;
+use tokio::time::delay_for;
+use zerocopy::LayoutVerified;
+
+type Replies = Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>;
+
+/// An asynchronous session with the vrouter generic netlink family.
+///
+/// Requests are written to a non-blocking socket registered with the tokio
+/// reactor and a background task routes every reply to its caller by
+/// `nlmsg_seq`, so any number of requests can be in flight at once.
+///
+/// The client must be created from within a tokio runtime with the I/O
+/// driver enabled. The background task stops when the client is dropped.
+/// It also stops when reading the socket fails, e.g. with ENOBUFS once the
+/// replies overrun the receive buffer: the requests in flight fail with that
+/// error and any later one with `BrokenPipe`.
+pub struct AsyncVrouterClient {
+    family_id: u16,
+    port_id: u32,
+    seq: AtomicU32,
+    retry: RetryPolicy,
+    socket: Arc<PollEvented<Socket>>,
+    pending: Arc<Mutex<InFlight>>,
+    _shutdown: oneshot::Sender<()>,
+}
+
+// The requests waiting for their reply, by sequence number. Once the reader
+// has stopped nothing would ever answer them, so `closed` turns any new
+// request away.
+#[derive(Default)]
+struct InFlight {
+    requests: HashMap<u32, Pending>,
+    closed: bool,
+}
+
+struct Pending {
+    collector: ReplyCollector,
+    reply: oneshot::Sender<Result<Replies, NetlinkError>>,
+}
+
+impl AsyncVrouterClient {
+    pub async fn new() -> Result<AsyncVrouterClient, MessageHandleError> {
+        let socket = Socket::new(Generic).map_err(NetlinkError::from)?;
+        AsyncVrouterClient::with_socket(socket).await
+    }
+
+    /// Open the client in another network namespace. Only the socket is
+    /// created there, on a short-lived thread; requests are then driven by
+    /// the runtime as usual.
+    pub async fn new_in(netns: &NetNs) -> Result<AsyncVrouterClient, MessageHandleError> {
+        let socket = in_netns(netns, || Socket::new(Generic));
+        let socket = socket.map_err(NetlinkError::from)?.map_err(NetlinkError::from)?;
+        AsyncVrouterClient::with_socket(socket).await
+    }
+
+    pub fn family_id(&self) -> u16 { self.family_id }
+
+    pub fn port_id(&self) -> u32 { self.port_id }
+
+    /// Send requests the vrouter turned down again, as `policy` allows. The
+    /// backoff waits on the runtime timer rather than blocking the thread.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) { self.retry = policy; }
+
+    pub fn retry_policy(&self) -> &RetryPolicy { &self.retry }
+
+    pub async fn send(
+        &self,
+        payload: &Message,
+    ) -> Result<Vec<Message>, MessageHandleError> {
+        let mut attempt = 1;
+        loop {
+            match self.send_once(payload).await {
+                Err(ref err) if self.retry.should_retry(payload, err, attempt) => {
+                    delay_for(self.retry.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn send_once(
+        &self,
+        payload: &Message,
+    ) -> Result<Vec<Message>, MessageHandleError> {
+        let request = payload.to_bytes()?;
+        let request_size = request.len();
+        let started = Instant::now();
+        let result = self.exchange(request).await;
+        notify(payload, request_size, started.elapsed(), result.as_deref());
+        result
+    }
+
+    async fn exchange(
+        &self,
+        payload: Vec<u8>,
+    ) -> Result<Vec<Message>, MessageHandleError> {
+        let nl_attr = &[NetlinkAttr::new(NL_ATTR_VR_MESSAGE_PROTOCOL, payload)] as &[_];
+        let (seq, buffer) = self.encode(self.family_id, SANDESH_REQUEST, nl_attr);
+        let mut replies = Vec::new();
+        for reply in sandesh_payloads(self.round_trip(seq, buffer).await?)? {
+            replies.extend(handle_sandesh_reply(&reply)?);
+        }
+        Ok(replies)
+    }
+
+    async fn with_socket(
+        mut socket: Socket,
+    ) -> Result<AsyncVrouterClient, MessageHandleError> {
+        let port_id = socket.bind_auto().map_err(NetlinkError::from)?.port_number();
+        // Older kernels lack extended ACKs, errors are still reported without
+        let _ = set_ext_ack(&socket, true);
+        socket.set_non_blocking(true).map_err(NetlinkError::from)?;
+        let socket = Arc::new(PollEvented::new(socket).map_err(NetlinkError::from)?);
+        let pending = Arc::new(Mutex::new(InFlight::default()));
+        let (shutdown, stopped) = oneshot::channel();
+        let reader_socket = socket.clone();
+        let recv = move |cx: &mut Context<'_>| poll_recv(&reader_socket, cx);
+        let reader = dispatch_replies(recv, pending.clone(), port_id);
+        tokio::spawn(async move {
+            futures::pin_mut!(reader);
+            future::select(stopped, reader).await;
+        });
+        let mut client = AsyncVrouterClient {
+            family_id: 0,
+            port_id,
+            seq: AtomicU32::new(1),
+            retry: RetryPolicy::never(),
+            socket,
+            pending,
+            _shutdown: shutdown,
+        };
+        client.family_id = client.resolve_family_id(VROUTER_GENETLINK_FAMILY_NAME).await?;
+        Ok(client)
+    }
+
+    async fn resolve_family_id(&self, name: &str) -> Result<u16, NetlinkError> {
+        let cstr_name = &CString::new(name).map_err(|e| {
+            NetlinkError::Io(io::Error::new(io::ErrorKind::InvalidInput, e))
+        })?;
+        let nl_attr = &[NetlinkAttr::new(CTRL_ATTR_FAMILY_NAME, cstr_name)] as &[_];
+        let (seq, buffer) = self.encode(GENL_ID_CTRL, CTRL_CMD_GETFAMILY, nl_attr);
+        let replies = self.round_trip(seq, buffer).await.map_err(|e| not_found(name, e))?;
+        family_from_reply(name, &replies).map(|family| family.id)
+    }
+
+    fn encode<P: Serialize>(&self, ty: u16, cmd: u8, payload: P) -> (u32, Vec<u8>) {
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+        let nl_msg = NetlinkMessage::new(
+            ty,
+            NLM_F_REQUEST,
+            GenericNetlinkMessage::new(cmd, NETLINK_VERSION, payload),
+        )
+        .with_seq(seq, self.port_id);
+        let mut buffer = vec![0; nl_msg.len() as usize];
+        nl_msg.serialize(&mut buffer[..]);
+        (seq, buffer)
+    }
+
+    async fn round_trip(
+        &self,
+        seq: u32,
+        buffer: Vec<u8>,
+    ) -> Result<Replies, NetlinkError> {
+        let response = lock(&self.pending).register(seq)?;
+        // Forget the request if the caller drops the future before the reply
+        let _guard = PendingGuard { seq, pending: &self.pending };
+        poll_fn(|cx| poll_send(&self.socket, cx, &buffer[..])).await?;
+        match response.await {
+            Ok(replies) => replies,
+            Err(_) => Err(reader_stopped()),
+        }
+    }
+}
+
+impl InFlight {
+    fn register(
+        &mut self,
+        seq: u32,
+    ) -> Result<oneshot::Receiver<Result<Replies, NetlinkError>>, NetlinkError> {
+        if self.closed {
+            return Err(reader_stopped());
+        }
+        let (reply, response) = oneshot::channel();
+        let pending = Pending {
+            collector: ReplyCollector::new(NLM_F_REQUEST),
+            reply,
+        };
+        self.requests.insert(seq, pending);
+        Ok(response)
+    }
+
+    // Hands each message of the datagram to the request carrying its
+    // sequence number. Replies nobody waits for any more (the caller went
+    // away) are dropped.
+    fn dispatch(&mut self, buffer: &[u8], port_id: u32) {
+        let mut seqs = Vec::new();
+        let mut offset = 0;
+        for nl_msg in deserialize_messages(buffer) {
+            let nl_msg = match nl_msg {
+                Ok(nl_msg) => nl_msg,
+                Err(err) => {
+                    // The rest of the datagram is lost, and with it the end
+                    // of every reply it carried
+                    seqs.extend(header_seq(&buffer[offset..], port_id));
+                    for seq in seqs {
+                        self.fail(seq, NetlinkError::Netlink(err.clone()));
+                    }
+                    return;
+                }
+            };
+            offset += NLMSG_ALIGN(NLMSG_LENGTH(nl_msg.payload.len() as u32)) as usize;
+            if nl_msg.pid != port_id {
+                continue;
+            }
+            seqs.push(nl_msg.seq);
+            let done = match self.requests.get_mut(&nl_msg.seq) {
+                Some(request) => request.collector.push(&nl_msg),
+                None => continue,
+            };
+            match done {
+                Ok(false) => (),
+                Ok(true) => {
+                    if let Some(request) = self.requests.remove(&nl_msg.seq) {
+                        let replies = request.collector.into_messages();
+                        let _ = request.reply.send(Ok(replies));
+                    }
+                }
+                Err(err) => self.fail(nl_msg.seq, err),
+            }
+        }
+    }
+
+    // Fails every request in flight with `err`, and any later one as well
+    fn close(&mut self, err: io::Error) {
+        self.closed = true;
+        for (_, request) in self.requests.drain() {
+            let err = match err.raw_os_error() {
+                Some(errno) => io::Error::from_raw_os_error(errno),
+                None => io::Error::new(err.kind(), err.to_string()),
+            };
+            let _ = request.reply.send(Err(NetlinkError::Io(err)));
+        }
+    }
+
+    fn fail(&mut self, seq: u32, err: NetlinkError) {
+        if let Some(request) = self.requests.remove(&seq) {
+            let _ = request.reply.send(Err(err));
+        }
+    }
+}
+
+// private functions
+
+struct PendingGuard<'a> {
+    seq: u32,
+    pending: &'a Mutex<InFlight>,
+}
+
+impl<'a> Drop for PendingGuard<'a> {
+    fn drop(&mut self) { lock(self.pending).requests.remove(&self.seq); }
+}
+
+fn lock(pending: &Mutex<InFlight>) -> MutexGuard<'_, InFlight> {
+    pending.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn reader_stopped() -> NetlinkError {
+    NetlinkError::Io(io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "the netlink reader has stopped",
+    ))
+}
+
+// The sequence number of a message whose header is whole but which is not
+fn header_seq(buf: &[u8], port_id: u32) -> Option<u32> {
+    let header = LayoutVerified::<_, nlmsghdr>::new_from_prefix(buf)?.0;
+    Some(header.nlmsg_seq).filter(|_| header.nlmsg_pid == port_id)
+}
+
+fn poll_send(
+    socket: &PollEvented<Socket>,
+    cx: &mut Context<'_>,
+    buf: &[u8],
+) -> Poll<io::Result<()>> {
+    ready!(socket.poll_write_ready(cx))?;
+    match socket.get_ref().send(buf, 0) {
+        Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+            socket.clear_write_ready(cx)?;
+            Poll::Pending
+        }
+        Err(e) => Poll::Ready(Err(e)),
+        Ok(_) => {
+            capture(Direction::Outgoing, buf);
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
+fn poll_recv(
+    socket: &PollEvented<Socket>,
+    cx: &mut Context<'_>,
+) -> Poll<io::Result<Vec<u8>>> {
+    ready!(socket.poll_read_ready(cx, Ready::readable()))?;
+    match recv_datagram(socket.get_ref()) {
+        Err(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+            socket.clear_read_ready(cx, Ready::readable())?;
+            Poll::Pending
+        }
+        result => Poll::Ready(result),
+    }
+}
+
+// Reads every datagram on the socket and dispatches its messages. A socket
+// error fails every request in flight and stops the reader.
+async fn dispatch_replies<R>(mut recv: R, pending: Arc<Mutex<InFlight>>, port_id: u32)
+where
+    R: FnMut(&mut Context<'_>) -> Poll<io::Result<Vec<u8>>>,
+{
+    loop {
+        match poll_fn(&mut recv).await {
+            Ok(buffer) => lock(&pending).dispatch(&buffer[..], port_id),
+            Err(err) => return lock(&pending).close(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlink::NLM_F_MULTI;
+    use futures::future::join_all;
+
+    const PORT_ID: u32 = 42;
+
+    fn reply(seq: u32, flags: u16) -> Vec<u8> {
+        let genl_msg =
+            GenericNetlinkMessage::new(SANDESH_REQUEST, NETLINK_VERSION, vec![]);
+        let nl_msg = NetlinkMessage::new(0x20, flags, genl_msg).with_seq(seq, PORT_ID);
+        let mut buffer = vec![0; nl_msg.len() as usize];
+        nl_msg.serialize(&mut buffer[..]);
+        buffer
+    }
+
+    #[tokio::test]
+    async fn socket_error_test() {
+        let pending = Arc::new(Mutex::new(InFlight::default()));
+        let responses = (1..=4).map(|seq| lock(&pending).register(seq).unwrap());
+        let responses = join_all(responses.collect::<Vec<_>>());
+        // Replies in full to the first request, in part to the second one,
+        // then overruns the receive buffer
+        let mut datagram = reply(1, 0);
+        datagram.extend(reply(2, NLM_F_MULTI as u16));
+        let mut datagrams = vec![
+            Ok(datagram),
+            Err(io::Error::from_raw_os_error(libc::ENOBUFS)),
+        ]
+        .into_iter();
+        let recv = move |_: &mut Context<'_>| match datagrams.next() {
+            Some(datagram) => Poll::Ready(datagram),
+            None => Poll::Pending,
+        };
+        let reader = dispatch_replies(recv, pending.clone(), PORT_ID);
+        let (responses, ()) = future::join(responses, reader).await;
+
+        let mut responses = responses.into_iter().map(Result::unwrap);
+        assert_eq!(responses.next().unwrap().unwrap()[0].seq, 1);
+        for response in responses {
+            match response {
+                Err(NetlinkError::Io(e)) => {
+                    assert_eq!(e.raw_os_error(), Some(libc::ENOBUFS))
+                }
+                res => panic!("unexpected result: {:?}", res),
+            }
+        }
+        // Nothing reads the socket any more, later requests fail at once
+        let response = lock(&pending).register(5);
+        match response.map(|_| ()) {
+            Err(NetlinkError::Io(e)) => assert_eq!(e.kind(), io::ErrorKind::BrokenPipe),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn malformed_header_test() {
+        let mut in_flight = InFlight::default();
+        let mut responses: Vec<_> =
+            (1..=4).map(|seq| in_flight.register(seq).unwrap()).collect();
+        // A header claiming more bytes than the datagram has left
+        let mut truncated = reply(3, 0);
+        truncated[..4].copy_from_slice(&64u32.to_ne_bytes());
+        let mut datagram = reply(1, NLM_F_MULTI as u16);
+        datagram.extend(reply(2, 0));
+        datagram.extend(truncated);
+        in_flight.dispatch(&datagram, PORT_ID);
+
+        for (seq, response) in (1..).zip(&mut responses[..3]) {
+            match (seq, response.try_recv().unwrap()) {
+                (2, Some(Ok(replies))) => assert_eq!(replies.len(), 1),
+                (_, Some(Err(NetlinkError::Netlink(_)))) if seq != 2 => (),
+                (_, res) => panic!("unexpected result for {}: {:?}", seq, res),
+            }
+        }
+        // Had no message in the datagram, still waits for its reply
+        assert_eq!(responses[3].try_recv().unwrap().is_none(), true);
+        assert_eq!(in_flight.closed, false);
+    }
+}
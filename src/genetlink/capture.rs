@@ -0,0 +1,96 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::NL_ATTR_VR_MESSAGE_PROTOCOL;
+use crate::netlink::pcap::{read_pcap, Direction};
+use crate::netlink::{deserialize_attrs, deserialize_messages, NetlinkAck, NetlinkError};
+use crate::netlink::{NETLINK_GENERIC, NLMSG_DONE, NLMSG_ERROR, NLMSG_NOOP};
+use crate::vr_messages::{CodecError, Message};
+use std::time::SystemTime;
+
+/// A vrouter netlink message found in a capture.
+#[derive(Debug)]
+pub struct CapturedMessage {
+    pub timestamp: SystemTime,
+    pub direction: Direction,
+    pub seq: u32,
+    pub pid: u32,
+    pub payload: CapturedPayload,
+}
+
+#[derive(Debug)]
+pub enum CapturedPayload {
+    /// The sandesh messages carried in NL_ATTR_VR_MESSAGE_PROTOCOL, in order
+    Sandesh(Vec<Message>),
+    /// NLMSG_ERROR, a plain acknowledgement when `errno` is 0
+    Ack(NetlinkAck),
+    /// A sandesh payload that does not decode
+    Invalid(CodecError),
+}
+
+/// Decodes the vrouter requests and replies of a pcap recorded on an `nlmon`
+/// interface, or by `netlink::start_capture`, into a timeline.
+///
+/// Frames of other netlink protocols and generic netlink messages without a
+/// sandesh payload (e.g. family lookups) are left out. Frames cut by the
+/// snapshot length keep the messages that fit.
+pub fn decode_capture(pcap: &[u8]) -> Result<Vec<CapturedMessage>, NetlinkError> {
+    let mut timeline = Vec::new();
+    for frame in read_pcap(pcap)? {
+        let frame = frame?;
+        if frame.protocol != NETLINK_GENERIC as u16 {
+            continue;
+        }
+        for nl_msg in deserialize_messages(frame.data) {
+            let nl_msg = match nl_msg {
+                Ok(nl_msg) => nl_msg,
+                Err(_) => break,
+            };
+            let payload = match nl_msg.ty as u32 {
+                NLMSG_DONE | NLMSG_NOOP => continue,
+                NLMSG_ERROR => {
+                    match NetlinkAck::deserialize(nl_msg.flags, nl_msg.payload) {
+                        Ok(ack) => CapturedPayload::Ack(ack),
+                        Err(_) => continue,
+                    }
+                }
+                _ => match sandesh_payload(nl_msg.payload) {
+                    Some(sandesh) => match decode_sandesh(sandesh) {
+                        Ok(messages) => CapturedPayload::Sandesh(messages),
+                        Err(err) => CapturedPayload::Invalid(err),
+                    },
+                    None => continue,
+                },
+            };
+            timeline.push(CapturedMessage {
+                timestamp: frame.timestamp,
+                direction: frame.direction,
+                seq: nl_msg.seq,
+                pid: nl_msg.pid,
+                payload,
+            });
+        }
+    }
+    Ok(timeline)
+}
+
+// private functions
+
+fn sandesh_payload(buf: &[u8]) -> Option<&[u8]> {
+    let genl_msg = GenericNetlinkMessage::deserialize(buf).ok()?;
+    for attr in deserialize_attrs(genl_msg.payload) {
+        match attr {
+            Ok((ty, value)) if ty == NL_ATTR_VR_MESSAGE_PROTOCOL => return Some(value),
+            Ok(_) => continue,
+            Err(_) => return None,
+        }
+    }
+    None
+}
+
+// A reply carries a vr_response followed by the requested objects, each
+// message starting where the previous one stopped reading.
+fn decode_sandesh(buf: &[u8]) -> Result<Vec<Message>, CodecError> {
+    Message::iter_from_slice(buf).collect()
+}
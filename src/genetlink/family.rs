@@ -0,0 +1,167 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::raw::*;
+use super::NETLINK_VERSION;
+use crate::netlink::{find_attr, parse_attrs, Attr, AttrKind};
+use crate::netlink::{set_ext_ack, NetlinkAttr, NetlinkError, NetlinkMessage, Serialize};
+use crate::netlink::{InvalidBuffer, NLM_F_DUMP, NLM_F_REQUEST};
+use libc::ENOENT;
+use netlink_sys::Protocol::Generic;
+use netlink_sys::Socket;
+use std::ffi::CString;
+use std::io;
+
+/// A generic netlink family as registered with the controller.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FamilyInfo {
+    pub id: u16,
+    pub name: String,
+    pub version: u32,
+    pub hdrsize: u32,
+    pub maxattr: u32,
+    pub ops: Vec<FamilyOp>,
+    pub mcast_groups: Vec<McastGroup>,
+}
+
+/// A command of the family, with its GENL_ADMIN_PERM/GENL_CMD_CAP_* flags.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct FamilyOp {
+    pub id: u32,
+    pub flags: u32,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct McastGroup {
+    pub name: String,
+    pub id: u32,
+}
+
+impl FamilyInfo {
+    /// Decodes the attributes of a CTRL_CMD_NEWFAMILY reply.
+    pub fn deserialize(buf: &[u8]) -> Result<FamilyInfo, InvalidBuffer> {
+        let attrs = parse_attrs(buf, CTRL_POLICY)?;
+        let attr = |ty| find_attr(&attrs, ty);
+        let entries = |ty| attr(ty).map(Attr::children).unwrap_or(&[]);
+        Ok(FamilyInfo {
+            id: attr(CTRL_ATTR_FAMILY_ID).and_then(Attr::as_u16).unwrap_or(0),
+            name: attr(CTRL_ATTR_FAMILY_NAME)
+                .and_then(Attr::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            version: attr(CTRL_ATTR_VERSION).and_then(Attr::as_u32).unwrap_or(0),
+            hdrsize: attr(CTRL_ATTR_HDRSIZE).and_then(Attr::as_u32).unwrap_or(0),
+            maxattr: attr(CTRL_ATTR_MAXATTR).and_then(Attr::as_u32).unwrap_or(0),
+            ops: entries(CTRL_ATTR_OPS).iter().map(FamilyOp::from_attr).collect(),
+            mcast_groups: entries(CTRL_ATTR_MCAST_GROUPS)
+                .iter()
+                .map(McastGroup::from_attr)
+                .collect(),
+        })
+    }
+
+    pub fn has_op(&self, cmd: u32) -> bool { self.ops.iter().any(|op| op.id == cmd) }
+}
+
+impl FamilyOp {
+    fn from_attr(entry: &Attr) -> FamilyOp {
+        FamilyOp {
+            id: entry.get(CTRL_ATTR_OP_ID).and_then(Attr::as_u32).unwrap_or(0),
+            flags: entry.get(CTRL_ATTR_OP_FLAGS).and_then(Attr::as_u32).unwrap_or(0),
+        }
+    }
+}
+
+impl McastGroup {
+    fn from_attr(entry: &Attr) -> McastGroup {
+        let name = entry.get(CTRL_ATTR_MCAST_GRP_NAME as u16).and_then(Attr::as_str);
+        let id = entry.get(CTRL_ATTR_MCAST_GRP_ID as u16).and_then(Attr::as_u32);
+        McastGroup {
+            name: name.unwrap_or_default().to_string(),
+            id: id.unwrap_or(0),
+        }
+    }
+}
+
+/// Looks a family up by name.
+///
+/// Fails with `NetlinkError::FamilyNotFound` when no such family is
+/// registered, e.g. because the vrouter module is not loaded.
+pub fn get_family(name: &str) -> Result<FamilyInfo, NetlinkError> {
+    let cstr_name = &CString::new(name)
+        .map_err(|e| NetlinkError::Io(io::Error::new(io::ErrorKind::InvalidInput, e)))?;
+    let nl_attr = &[NetlinkAttr::new(CTRL_ATTR_FAMILY_NAME, cstr_name)] as &[_];
+    let replies = ctrl_request(NLM_F_REQUEST, nl_attr).map_err(|e| not_found(name, e))?;
+    family_from_reply(name, &replies)
+}
+
+/// Dumps every family registered with the generic netlink controller.
+pub fn list_families() -> Result<Vec<FamilyInfo>, NetlinkError> {
+    let replies = ctrl_request(NLM_F_REQUEST | NLM_F_DUMP, Vec::new())?;
+    let mut families = Vec::with_capacity(replies.len());
+    for nl_msg in replies {
+        families.push(FamilyInfo::deserialize(&nl_msg.payload.payload[..])?);
+    }
+    Ok(families)
+}
+
+pub(crate) fn family_from_reply(
+    name: &str,
+    replies: &[NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>],
+) -> Result<FamilyInfo, NetlinkError> {
+    for nl_msg in replies {
+        let family = FamilyInfo::deserialize(&nl_msg.payload.payload[..])?;
+        if family.id != 0 {
+            return Ok(family);
+        }
+    }
+    Err(NetlinkError::FamilyNotFound(name.to_string()))
+}
+
+// The controller answers a lookup of an unregistered family with ENOENT.
+pub(crate) fn not_found(name: &str, err: NetlinkError) -> NetlinkError {
+    match err {
+        NetlinkError::Kernel(ref ack) if ack.errno == ENOENT => {
+            NetlinkError::FamilyNotFound(name.to_string())
+        }
+        err => err,
+    }
+}
+
+// private functions
+
+const OP_POLICY: &[(u16, AttrKind)] =
+    &[(CTRL_ATTR_OP_ID, AttrKind::U32), (CTRL_ATTR_OP_FLAGS, AttrKind::U32)];
+
+const MCAST_GRP_POLICY: &[(u16, AttrKind)] = &[
+    (CTRL_ATTR_MCAST_GRP_NAME as u16, AttrKind::String),
+    (CTRL_ATTR_MCAST_GRP_ID as u16, AttrKind::U32),
+];
+
+const CTRL_POLICY: &[(u16, AttrKind)] = &[
+    (CTRL_ATTR_FAMILY_ID, AttrKind::U16),
+    (CTRL_ATTR_FAMILY_NAME, AttrKind::String),
+    (CTRL_ATTR_VERSION, AttrKind::U32),
+    (CTRL_ATTR_HDRSIZE, AttrKind::U32),
+    (CTRL_ATTR_MAXATTR, AttrKind::U32),
+    (CTRL_ATTR_OPS, AttrKind::Array(OP_POLICY)),
+    (CTRL_ATTR_MCAST_GROUPS, AttrKind::Array(MCAST_GRP_POLICY)),
+];
+
+fn ctrl_request<P: Serialize>(
+    flags: u16,
+    payload: P,
+) -> Result<Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>, NetlinkError> {
+    let mut socket = Socket::new(Generic)?;
+    let port_id = socket.bind_auto()?.port_number();
+    let nl_msg = NetlinkMessage::new(
+        GENL_ID_CTRL,
+        flags,
+        GenericNetlinkMessage::new(CTRL_CMD_GETFAMILY, NETLINK_VERSION, payload),
+    )
+    .with_seq(1, port_id);
+    let _ = set_ext_ack(&socket, true);
+    nl_msg.send_nl(&socket)?;
+    nl_msg.recv_nl(&socket)
+}
@@ -42,8 +42,8 @@ impl<'a> GenericNetlinkMessage<&'a [u8]> {
             return Err(InvalidBuffer::Header(buf.len()));
         }
         let (header, payload) = buf.split_at(header_len);
-        let header =
-            LayoutVerified::<_, genlmsghdr>::new(header).expect("invalid buffer");
+        let header = LayoutVerified::<_, genlmsghdr>::new(header)
+            .ok_or(InvalidBuffer::Header(buf.len()))?;
         Ok(Self {
             cmd: header.cmd,
             version: header.version,
@@ -1,17 +1,35 @@
+pub mod async_client;
+pub mod capture;
+pub mod family;
 pub mod message;
+pub mod observer;
+pub mod pool;
 pub mod raw;
-
+pub mod retry;
+pub mod socket;
+pub mod transport;
+pub mod unix_socket;
+
+pub use self::async_client::AsyncVrouterClient;
+pub use self::capture::{decode_capture, CapturedMessage, CapturedPayload};
+pub use self::family::{get_family, list_families, FamilyInfo, FamilyOp, McastGroup};
 pub use self::message::GenericNetlinkMessage;
+pub use self::observer::{clear_observer, set_observer, Observer, RoundTrip};
+pub use self::pool::VrouterPool;
 pub use self::raw::*;
+pub use self::retry::{send_sandesh_batch_with_retry, send_sandesh_msg_with_retry};
+pub use self::retry::RetryPolicy;
+pub use self::socket::VrouterSocket;
+pub use self::transport::{send_sandesh_batch_with, send_sandesh_msg_with};
+pub use self::transport::{BatchReplies, Transport};
+pub use self::unix_socket::{VrouterUnixSocket, DPDK_NETLINK_SOCKET_PATH};
 pub use crate::netlink::raw::*;
-use crate::netlink::NetlinkError;
-use crate::netlink::{deserialize_attrs, deserialize_u16};
-use crate::netlink::{NetlinkAttr, NetlinkMessage};
+use crate::netlink::{in_netns, NetNs, NetlinkAck, NetlinkError};
 pub use crate::vr_messages::*;
+use crate::vr_messages::binary_protocol::sandesh_len;
 use libc::{EBUSY, EEXIST, EINVAL, ENODEV, ENOENT, ENOMEM, ENOSPC, EOPNOTSUPP};
-use netlink_sys::Protocol::Generic;
-use netlink_sys::Socket;
-use std::ffi::CString;
+use std::path::Path;
+use std::{env, io};
 use thiserror::Error;
 
 pub const VROUTER_GENETLINK_FAMILY_NAME: &str = "vrouter";
@@ -19,63 +37,144 @@ pub const NL_ATTR_VR_MESSAGE_PROTOCOL: u16 = 1;
 pub const SANDESH_REQUEST: u8 = 1;
 pub const NETLINK_VERSION: u8 = 2;
 
+/// Fails with `NetlinkError::FamilyNotFound` when no such family is
+/// registered, e.g. because the vrouter module is not loaded.
 pub fn resolve_family_id(name: &str) -> Result<u16, NetlinkError> {
-    let cstr_name = &CString::new(name).unwrap();
-    let nl_attr = &[NetlinkAttr::new(CTRL_ATTR_FAMILY_NAME, cstr_name)] as &[_];
-    let nl_msg = NetlinkMessage::new(
-        GENL_ID_CTRL,
-        NLM_F_REQUEST,
-        GenericNetlinkMessage::new(CTRL_CMD_GETFAMILY, NETLINK_VERSION, nl_attr),
-    );
-    let socket = &Socket::new(Generic)?;
-    nl_msg.send_nl(socket);
-    let nl_msg = NetlinkMessage::<Vec<u8>>::recv_nl(socket);
-    for attr in deserialize_attrs(&nl_msg.payload.payload[..]) {
-        let (ty, value) = attr?;
-        if ty == CTRL_ATTR_FAMILY_ID {
-            return Ok(deserialize_u16(value)?);
+    get_family(name).map(|family| family.id)
+}
+
+/// Sends `payload` to the vrouter `Datapath::detect` picks, see
+/// `VrouterSession::open`.
+pub fn send_sandesh_msg(payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
+    VrouterSession::open()?.send(payload)
+}
+
+/// Same as `send_sandesh_msg`, for several requests sent together.
+pub fn send_sandesh_batch(
+    payloads: &[Message],
+) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+    send_sandesh_batch_with(&VrouterSession::open()?, payloads)
+}
+
+/// Picks the datapath of `Datapath::detect`, `kernel` or `dpdk`.
+pub const VROUTER_DATAPATH_ENV: &str = "VROUTER_DATAPATH";
+
+/// The vrouter a `VrouterSession` talks to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Datapath {
+    /// The vrouter kernel module, over generic netlink.
+    Kernel,
+    /// The DPDK vrouter, over `DPDK_NETLINK_SOCKET_PATH`.
+    Dpdk,
+}
+
+impl Datapath {
+    /// The datapath `VROUTER_DATAPATH` names when set. Otherwise the DPDK
+    /// vrouter when its netlink socket exists, and the kernel module if not.
+    pub fn detect() -> Result<Datapath, MessageHandleError> {
+        match env::var(VROUTER_DATAPATH_ENV) {
+            Ok(name) => match name.as_str() {
+                "kernel" => Ok(Datapath::Kernel),
+                "dpdk" => Ok(Datapath::Dpdk),
+                _ => Err(MessageHandleError::UnknownDatapath(name)),
+            },
+            Err(_) if Path::new(DPDK_NETLINK_SOCKET_PATH).exists() => Ok(Datapath::Dpdk),
+            Err(_) => Ok(Datapath::Kernel),
         }
     }
+}
 
-    Ok(0)
+/// A session with the kernel or the DPDK vrouter.
+pub enum VrouterSession {
+    Kernel(VrouterSocket),
+    Dpdk(VrouterUnixSocket),
 }
 
-pub fn send_sandesh_msg(payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
-    let nl_attr = &[NetlinkAttr::new(NL_ATTR_VR_MESSAGE_PROTOCOL, payload)] as &[_];
-    let nl_msg = NetlinkMessage::new(
-        resolve_family_id(VROUTER_GENETLINK_FAMILY_NAME).unwrap(),
-        NLM_F_REQUEST,
-        GenericNetlinkMessage::new(SANDESH_REQUEST, NETLINK_VERSION, nl_attr),
-    );
-    let socket = &Socket::new(Generic).unwrap();
-    nl_msg.send_nl(socket);
-    let nl_msg = NetlinkMessage::<Vec<u8>>::recv_nl(socket);
-    handle_genl_reply(&nl_msg.payload.payload[..])
+impl VrouterSession {
+    /// Connect to the vrouter of `Datapath::detect`.
+    pub fn open() -> Result<VrouterSession, MessageHandleError> {
+        VrouterSession::connect(Datapath::detect()?)
+    }
+
+    pub fn connect(datapath: Datapath) -> Result<VrouterSession, MessageHandleError> {
+        match datapath {
+            Datapath::Kernel => Ok(VrouterSession::Kernel(VrouterSocket::new()?)),
+            Datapath::Dpdk => Ok(VrouterSession::Dpdk(VrouterUnixSocket::new()?)),
+        }
+    }
+
+    pub fn datapath(&self) -> Datapath {
+        match self {
+            VrouterSession::Kernel(_) => Datapath::Kernel,
+            VrouterSession::Dpdk(_) => Datapath::Dpdk,
+        }
+    }
+
+    /// Same as `open`, with the kernel session opened in `netns`.
+    pub fn open_in(netns: &NetNs) -> Result<VrouterSession, MessageHandleError> {
+        in_netns(netns, VrouterSession::open).map_err(NetlinkError::from)?
+    }
+
+    /// Send requests the vrouter turned down again, as `policy` allows.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        match self {
+            VrouterSession::Kernel(socket) => socket.set_retry_policy(policy),
+            VrouterSession::Dpdk(socket) => socket.set_retry_policy(policy),
+        }
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        match self {
+            VrouterSession::Kernel(socket) => socket.retry_policy(),
+            VrouterSession::Dpdk(socket) => socket.retry_policy(),
+        }
+    }
+
+    pub fn send(&self, payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
+        send_sandesh_msg_with_retry(self, payload, self.retry_policy())
+    }
+
+    pub fn send_batch(
+        &self,
+        payloads: &[Message],
+    ) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+        send_sandesh_batch_with_retry(self, payloads, self.retry_policy())
+    }
 }
 
-// private functions
+impl Transport for VrouterSession {
+    fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+        match self {
+            VrouterSession::Kernel(socket) => socket.request(payload),
+            VrouterSession::Dpdk(socket) => socket.request(payload),
+        }
+    }
 
-fn handle_genl_reply<'a>(buf: &'a [u8]) -> Result<Vec<Message>, MessageHandleError> {
-    for attr in deserialize_attrs(&buf) {
-        let (ty, value) = attr.unwrap();
-        if ty == NL_ATTR_VR_MESSAGE_PROTOCOL {
-            return handle_sandesh_reply(value.to_vec());
+    fn request_batch(
+        &self,
+        payloads: &[Vec<u8>],
+    ) -> Result<BatchReplies, MessageHandleError> {
+        match self {
+            VrouterSession::Kernel(socket) => socket.request_batch(payloads),
+            VrouterSession::Dpdk(socket) => socket.request_batch(payloads),
         }
     }
-    return Ok(vec![]);
 }
 
-fn handle_sandesh_reply(buf: Vec<u8>) -> Result<Vec<Message>, MessageHandleError> {
-    let vec: &mut Vec<Message> = &mut Vec::new();
-    let resp = handle_header_message(&buf)?;
-    for message in decode_messages(resp.read_length(), &buf[..]) {
-        vec.push(message)
+// private functions
+
+fn handle_sandesh_reply(buf: &[u8]) -> Result<Vec<Message>, MessageHandleError> {
+    handle_header_message(buf)?;
+    let objects = &buf[sandesh_len(buf)?..];
+    let mut messages = Vec::new();
+    for message in Message::iter_from_slice(objects) {
+        messages.push(message?);
     }
-    Ok(vec.to_vec())
+    Ok(messages)
 }
 
-fn handle_header_message(buf: &Vec<u8>) -> Result<Message, MessageHandleError> {
-    match Message::from_bytes(buf.to_vec())? {
+fn handle_header_message(buf: &[u8]) -> Result<Message, MessageHandleError> {
+    match Message::from_slice(buf)? {
         Message::VrResponse(resp) => handle_vr_response(resp),
         _ => Err(MessageHandleError::MessageOutOfOrder),
     }
@@ -102,37 +201,6 @@ fn handle_error(resp_code: i32) -> OperationError {
     }
 }
 
-fn decode_messages(pos: usize, buf: &[u8]) -> VrMessageIter<'_> {
-    VrMessageIter {
-        prev_read_pos: pos,
-        buf: buf,
-    }
-}
-
-struct VrMessageIter<'a> {
-    prev_read_pos: usize,
-    buf: &'a [u8],
-}
-
-impl<'a> Iterator for VrMessageIter<'a> {
-    type Item = Message;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.buf.to_vec().len() < self.prev_read_pos {
-            return None;
-        }
-        let (_prev, leftover) = self.buf.split_at(self.prev_read_pos);
-        match Message::from_bytes(leftover.to_vec()) {
-            Err(_err) => None,
-            Ok(req) => {
-                self.prev_read_pos = req.read_length();
-                self.buf = leftover;
-                Some(req)
-            }
-        }
-    }
-}
-
 #[derive(Debug, Error)]
 pub enum MessageHandleError {
     #[error("Invalid Sandesh message received")]
@@ -140,7 +208,33 @@ pub enum MessageHandleError {
     #[error("The request has failed")]
     RequestError(#[from] OperationError),
     #[error("The Netlink operation has failed")]
-    NetlinkError(#[from] NetlinkError),
+    NetlinkError(#[source] NetlinkError),
+    #[error("The kernel rejected the request: {0}")]
+    KernelError(NetlinkAck),
     #[error("Expected that a vr_message comes first")]
     MessageOutOfOrder,
+    #[error("No reply from the vrouter before the receive timeout")]
+    Timeout,
+    #[error("The generic netlink family {0} is not registered")]
+    FamilyNotFound(String),
+    #[error("Unknown vrouter datapath {0:?}, expected kernel or dpdk")]
+    UnknownDatapath(String),
+    #[error("The reply holds no {0:?}")]
+    MissingReply(MessageType),
+}
+
+impl From<NetlinkError> for MessageHandleError {
+    fn from(err: NetlinkError) -> MessageHandleError {
+        match err {
+            NetlinkError::Kernel(ack) => MessageHandleError::KernelError(ack),
+            NetlinkError::FamilyNotFound(name) => {
+                MessageHandleError::FamilyNotFound(name)
+            }
+            // SO_RCVTIMEO expiry and an empty non-blocking socket alike
+            NetlinkError::Io(ref e) if e.kind() == io::ErrorKind::WouldBlock => {
+                MessageHandleError::Timeout
+            }
+            err => MessageHandleError::NetlinkError(err),
+        }
+    }
 }
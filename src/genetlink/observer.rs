@@ -0,0 +1,92 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::MessageHandleError;
+use crate::vr_messages::{Message, MessageType, OperationError, SandeshOp};
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// What happened to one sandesh request, reported once its replies are in.
+#[derive(Debug)]
+pub struct RoundTrip<'a> {
+    pub message_type: MessageType,
+    pub op: SandeshOp,
+    /// Size of the encoded sandesh request, without the netlink headers
+    pub request_size: usize,
+    /// Messages that came back after the vr_response, zero on failure
+    pub reply_count: usize,
+    /// From sending the request to decoding its last reply. Requests of a
+    /// batch all report the latency of the whole batch.
+    pub latency: Duration,
+    pub error: Option<&'a MessageHandleError>,
+}
+
+impl<'a> RoundTrip<'a> {
+    /// The error code the vrouter answered with, if it turned the request
+    /// down.
+    pub fn operation_error(&self) -> Option<OperationError> {
+        match self.error {
+            Some(MessageHandleError::RequestError(err)) => Some(*err),
+            _ => None,
+        }
+    }
+}
+
+/// Receives every sandesh round-trip of the process, e.g. to export latency
+/// histograms and error counts per object type.
+///
+/// Observers are called on the thread that made the request, right after
+/// its replies are decoded, so they should be quick.
+pub trait Observer: Send + Sync {
+    fn on_round_trip(&self, round_trip: &RoundTrip);
+}
+
+impl<F: Fn(&RoundTrip) + Send + Sync> Observer for F {
+    fn on_round_trip(&self, round_trip: &RoundTrip) { self(round_trip) }
+}
+
+/// Report every round-trip of every session to `observer`, in place of any
+/// previous one.
+pub fn set_observer<O: Observer + 'static>(observer: O) {
+    let mut current = OBSERVER.write().unwrap_or_else(|e| e.into_inner());
+    *current = Some(Arc::new(observer));
+    OBSERVING.store(true, Ordering::Release);
+}
+
+pub fn clear_observer() {
+    OBSERVING.store(false, Ordering::Release);
+    let mut current = OBSERVER.write().unwrap_or_else(|e| e.into_inner());
+    *current = None;
+}
+
+// Reports a round-trip to the observer, if one is set. The observer runs
+// outside the lock, so it may replace itself.
+pub(crate) fn notify(
+    request: &Message,
+    request_size: usize,
+    latency: Duration,
+    result: Result<&[Message], &MessageHandleError>,
+) {
+    if !OBSERVING.load(Ordering::Acquire) {
+        return;
+    }
+    let observer = OBSERVER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(observer) = observer {
+        observer.on_round_trip(&RoundTrip {
+            message_type: request.message_type(),
+            op: request.sandesh_op(),
+            request_size,
+            reply_count: result.map(<[Message]>::len).unwrap_or(0),
+            latency,
+            error: result.err(),
+        });
+    }
+}
+
+// private functions
+
+static OBSERVING: AtomicBool = AtomicBool::new(false);
+static OBSERVER: Lazy<RwLock<Option<Arc<dyn Observer>>>> =
+    Lazy::new(|| RwLock::new(None));
@@ -0,0 +1,126 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::retry::{send_sandesh_batch_with_retry, send_sandesh_msg_with_retry};
+use super::retry::RetryPolicy;
+use super::transport::{BatchReplies, Transport};
+use super::{MessageHandleError, VrouterSession};
+use crate::netlink::NetNs;
+use crate::vr_messages::Message;
+use std::ops::Deref;
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+/// A client to share between threads, backed by a pool of sessions.
+///
+/// Every request checks an idle session out of the pool for the time of its
+/// round-trip, so up to `size` requests run in parallel, each on its own
+/// socket, and the others wait for a session to come back. The pool lock
+/// is only held to pick a session, never across a round-trip.
+pub struct VrouterPool<T: Transport = VrouterSession> {
+    sessions: Vec<T>,
+    idle: Mutex<Vec<usize>>,
+    returned: Condvar,
+    retry: RetryPolicy,
+}
+
+impl VrouterPool<VrouterSession> {
+    /// Open `size` sessions with whichever vrouter datapath runs on this host.
+    pub fn open(size: usize) -> Result<VrouterPool, MessageHandleError> {
+        let sessions = (0..size.max(1)).map(|_| VrouterSession::open());
+        Ok(VrouterPool::from_sessions(sessions.collect::<Result<_, _>>()?))
+    }
+
+    /// Same as `open`, with the sessions opened in `netns`.
+    pub fn open_in(
+        netns: &NetNs,
+        size: usize,
+    ) -> Result<VrouterPool, MessageHandleError> {
+        let sessions = (0..size.max(1)).map(|_| VrouterSession::open_in(netns));
+        Ok(VrouterPool::from_sessions(sessions.collect::<Result<_, _>>()?))
+    }
+}
+
+impl<T: Transport> VrouterPool<T> {
+    /// Pool sessions opened by the caller, e.g. with their own timeouts.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `sessions` is empty.
+    pub fn from_sessions(sessions: Vec<T>) -> VrouterPool<T> {
+        assert!(!sessions.is_empty(), "a pool needs at least one session");
+        VrouterPool {
+            idle: Mutex::new((0..sessions.len()).rev().collect()),
+            sessions,
+            returned: Condvar::new(),
+            retry: RetryPolicy::never(),
+        }
+    }
+
+    pub fn size(&self) -> usize { self.sessions.len() }
+
+    /// Send requests the vrouter turned down again, as `policy` allows. Each
+    /// attempt checks a session out anew.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) { self.retry = policy; }
+
+    pub fn retry_policy(&self) -> &RetryPolicy { &self.retry }
+
+    pub fn send(&self, payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
+        send_sandesh_msg_with_retry(self, payload, &self.retry)
+    }
+
+    /// Sends the whole batch through one session.
+    pub fn send_batch(
+        &self,
+        payloads: &[Message],
+    ) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+        send_sandesh_batch_with_retry(self, payloads, &self.retry)
+    }
+
+    // private functions
+
+    fn checkout(&self) -> PooledSession<'_, T> {
+        let mut idle = self.lock_idle();
+        loop {
+            if let Some(index) = idle.pop() {
+                return PooledSession { pool: self, index };
+            }
+            idle = self.returned.wait(idle).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn lock_idle(&self) -> MutexGuard<'_, Vec<usize>> {
+        self.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: Transport> Transport for VrouterPool<T> {
+    fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+        self.checkout().request(payload)
+    }
+
+    fn request_batch(
+        &self,
+        payloads: &[Vec<u8>],
+    ) -> Result<BatchReplies, MessageHandleError> {
+        self.checkout().request_batch(payloads)
+    }
+}
+
+// A session checked out of the pool, handed back when dropped.
+struct PooledSession<'a, T: Transport> {
+    pool: &'a VrouterPool<T>,
+    index: usize,
+}
+
+impl<'a, T: Transport> Deref for PooledSession<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T { &self.pool.sessions[self.index] }
+}
+
+impl<'a, T: Transport> Drop for PooledSession<'a, T> {
+    fn drop(&mut self) {
+        self.pool.lock_idle().push(self.index);
+        self.pool.returned.notify_one();
+    }
+}
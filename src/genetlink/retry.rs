@@ -0,0 +1,123 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::transport::{send_sandesh_batch_with, send_sandesh_msg_with, Transport};
+use super::MessageHandleError;
+use crate::vr_messages::{Message, OperationError, SandeshOp};
+use std::thread;
+use std::time::Duration;
+
+/// When, and how often, a request the vrouter turned down is sent again.
+///
+/// Only errors listed in `retryable` are retried, with a backoff doubling
+/// from `initial_backoff` up to `max_backoff`. An `Add` failing with EEXIST
+/// or a `Del` failing with ENOENT is never retried: it reports the state of
+/// the object, and after a first attempt it may well mean that attempt went
+/// through.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first one
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub retryable: Vec<OperationError>,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 5 attempts on EBUSY and ENOMEM, which the vrouter returns while
+    /// flows drain or during bulk deletes.
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            retryable: vec![OperationError::EBUSY, OperationError::ENOMEM],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Every request is sent once, which is what sessions do by default.
+    pub fn never() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Whether `request`, failed with `err` at its `attempt`th try (from 1),
+    /// is worth sending again.
+    pub fn should_retry(
+        &self,
+        request: &Message,
+        err: &MessageHandleError,
+        attempt: u32,
+    ) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        let err = match err {
+            MessageHandleError::RequestError(err) => err,
+            _ => return false,
+        };
+        match (request.sandesh_op(), err) {
+            (SandeshOp::Add, OperationError::EEXIST) => false,
+            (SandeshOp::Del, OperationError::ENOENT) => false,
+            (_, err) => self.retryable.contains(err),
+        }
+    }
+
+    /// How long to wait after the `attempt`th try (from 1) failed.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let backoff = self.initial_backoff.checked_mul(factor);
+        backoff.unwrap_or(self.max_backoff).min(self.max_backoff)
+    }
+}
+
+/// Same as `send_sandesh_msg_with`, sending the request again as long as
+/// `policy` allows.
+pub fn send_sandesh_msg_with_retry(
+    transport: &dyn Transport,
+    payload: &Message,
+    policy: &RetryPolicy,
+) -> Result<Vec<Message>, MessageHandleError> {
+    let result = send_sandesh_msg_with(transport, payload);
+    retry(transport, payload, policy, result)
+}
+
+/// Same as `send_sandesh_batch_with`, the failed requests are retried one by
+/// one as long as `policy` allows.
+pub fn send_sandesh_batch_with_retry(
+    transport: &dyn Transport,
+    payloads: &[Message],
+    policy: &RetryPolicy,
+) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+    let results = send_sandesh_batch_with(transport, payloads)?;
+    Ok(results
+        .into_iter()
+        .zip(payloads)
+        .map(|(result, payload)| retry(transport, payload, policy, result))
+        .collect())
+}
+
+// private functions
+
+fn retry(
+    transport: &dyn Transport,
+    payload: &Message,
+    policy: &RetryPolicy,
+    mut result: Result<Vec<Message>, MessageHandleError>,
+) -> Result<Vec<Message>, MessageHandleError> {
+    let mut attempt = 1;
+    loop {
+        match result {
+            Err(ref err) if policy.should_retry(payload, err, attempt) => {
+                thread::sleep(policy.backoff(attempt));
+                attempt += 1;
+                result = send_sandesh_msg_with(transport, payload);
+            }
+            result => return result,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::retry::{send_sandesh_batch_with_retry, send_sandesh_msg_with_retry};
+use super::retry::RetryPolicy;
+use super::transport::{sandesh_payloads, BatchReplies, Transport};
+use super::{resolve_family_id, MessageHandleError};
+use super::{NETLINK_VERSION, NL_ATTR_VR_MESSAGE_PROTOCOL, SANDESH_REQUEST};
+use super::VROUTER_GENETLINK_FAMILY_NAME;
+use crate::netlink::{set_ext_ack, set_recv_buffer_size, set_recv_timeout};
+use crate::netlink::{in_netns, NetNs, NetlinkAttr, NetlinkError, NetlinkMessage};
+use crate::netlink::{recv_batch_nl, send_batch_nl, NLM_F_ACK, NLM_F_REQUEST};
+use crate::vr_messages::Message;
+use netlink_sys::Protocol::Generic;
+use netlink_sys::Socket;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const DEFAULT_BATCH_SIZE: usize = 64;
+
+type Replies = Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>;
+
+/// A long-lived session with the vrouter generic netlink family.
+///
+/// The family id is resolved once when the session is opened and every
+/// request goes through the same bound socket, so bulk programming does not
+/// pay for a CTRL_CMD_GETFAMILY round-trip and two sockets per message.
+///
+/// Each request carries the next sequence number of the session and its
+/// reply is matched by `nlmsg_seq`/`nlmsg_pid`. Round-trips are serialised,
+/// so the session can be shared between threads.
+pub struct VrouterSocket {
+    family_id: u16,
+    port_id: u32,
+    ack: bool,
+    batch_size: usize,
+    retry: RetryPolicy,
+    seq: AtomicU32,
+    socket: Mutex<Socket>,
+}
+
+impl VrouterSocket {
+    pub fn new() -> Result<VrouterSocket, MessageHandleError> {
+        let family_id = resolve_family_id(VROUTER_GENETLINK_FAMILY_NAME)?;
+        let mut socket = Socket::new(Generic).map_err(NetlinkError::from)?;
+        let port_id = socket.bind_auto().map_err(NetlinkError::from)?.port_number();
+        // Older kernels lack extended ACKs, errors are still reported without
+        let _ = set_ext_ack(&socket, true);
+        Ok(VrouterSocket {
+            family_id,
+            port_id,
+            ack: false,
+            batch_size: DEFAULT_BATCH_SIZE,
+            retry: RetryPolicy::never(),
+            seq: AtomicU32::new(1),
+            socket: Mutex::new(socket),
+        })
+    }
+
+    /// Open the session in another network namespace, e.g. to program the
+    /// vrouter of a test topology. The calling thread stays where it is.
+    pub fn new_in(netns: &NetNs) -> Result<VrouterSocket, MessageHandleError> {
+        in_netns(netns, VrouterSocket::new).map_err(NetlinkError::from)?
+    }
+
+    pub fn family_id(&self) -> u16 { self.family_id }
+
+    pub fn port_id(&self) -> u32 { self.port_id }
+
+    /// Request an explicit acknowledgement (NLM_F_ACK) for every message.
+    pub fn set_ack(&mut self, ack: bool) { self.ack = ack; }
+
+    /// Cap the number of requests `send_batch` packs into one datagram.
+    ///
+    /// The replies to a whole datagram are queued on the socket before they
+    /// are read, so the batch must stay small enough for the receive buffer.
+    pub fn set_batch_size(&mut self, batch_size: usize) {
+        self.batch_size = batch_size.max(1);
+    }
+
+    /// Send requests the vrouter turned down again, as `policy` allows. By
+    /// default every request is sent once.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) { self.retry = policy; }
+
+    pub fn retry_policy(&self) -> &RetryPolicy { &self.retry }
+
+    /// Give up on a reply after `timeout`, with `MessageHandleError::Timeout`.
+    /// `None` waits forever, which is the default.
+    ///
+    /// A reply that arrives after its request timed out is discarded by the
+    /// next request.
+    pub fn set_recv_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<(), MessageHandleError> {
+        let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(set_recv_timeout(&socket, timeout)?)
+    }
+
+    /// Grow the receive buffer, so that large dumps and batches of replies
+    /// are not dropped by the kernel.
+    pub fn set_recv_buffer_size(&self, size: usize) -> Result<(), MessageHandleError> {
+        let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(set_recv_buffer_size(&socket, size)?)
+    }
+
+    /// In non-blocking mode a request whose reply is not already queued
+    /// fails at once with `MessageHandleError::Timeout`.
+    pub fn set_non_blocking(&self, non_blocking: bool) -> Result<(), MessageHandleError> {
+        let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+        socket.set_non_blocking(non_blocking).map_err(NetlinkError::from)?;
+        Ok(())
+    }
+
+    pub fn send(&self, payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
+        send_sandesh_msg_with_retry(self, payload, &self.retry)
+    }
+
+    /// Send many requests with a single syscall per `batch_size` of them and
+    /// return the outcome of each one, in order.
+    pub fn send_batch(
+        &self,
+        payloads: &[Message],
+    ) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+        send_sandesh_batch_with_retry(self, payloads, &self.retry)
+    }
+
+    // private functions
+
+    fn round_trip(&self, payload: Vec<u8>) -> Result<Replies, NetlinkError> {
+        let nl_msg = self.request_message(payload);
+        let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+        nl_msg.send_nl(&socket)?;
+        nl_msg.recv_nl(&socket)
+    }
+
+    fn round_trip_batch(
+        &self,
+        payloads: &[Vec<u8>],
+    ) -> Result<Vec<Result<Replies, NetlinkError>>, NetlinkError> {
+        let socket = self.socket.lock().unwrap_or_else(|e| e.into_inner());
+        let mut replies = Vec::with_capacity(payloads.len());
+        for chunk in payloads.chunks(self.batch_size) {
+            let nl_msgs: Vec<_> = chunk
+                .iter()
+                .map(|payload| self.request_message(payload.clone()))
+                .collect();
+            send_batch_nl(&nl_msgs, &socket)?;
+            replies.extend(recv_batch_nl(&nl_msgs, &socket)?);
+        }
+        Ok(replies)
+    }
+
+    fn request_message(
+        &self,
+        payload: Vec<u8>,
+    ) -> NetlinkMessage<GenericNetlinkMessage<NetlinkAttr<Vec<u8>>>> {
+        let nl_attr = NetlinkAttr::new(NL_ATTR_VR_MESSAGE_PROTOCOL, payload);
+        let flags = if self.ack { NLM_F_REQUEST | NLM_F_ACK } else { NLM_F_REQUEST };
+        NetlinkMessage::new(
+            self.family_id,
+            flags,
+            GenericNetlinkMessage::new(SANDESH_REQUEST, NETLINK_VERSION, nl_attr),
+        )
+        .with_seq(self.next_seq(), self.port_id)
+    }
+
+    fn next_seq(&self) -> u32 { self.seq.fetch_add(1, Ordering::Relaxed) }
+}
+
+impl Transport for VrouterSocket {
+    fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+        Ok(sandesh_payloads(self.round_trip(payload.to_vec())?)?)
+    }
+
+    fn request_batch(
+        &self,
+        payloads: &[Vec<u8>],
+    ) -> Result<BatchReplies, MessageHandleError> {
+        let replies = self.round_trip_batch(payloads)?.into_iter();
+        Ok(replies.map(|reply| Ok(sandesh_payloads(reply?)?)).collect())
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::observer::notify;
+use super::{handle_sandesh_reply, MessageHandleError, NL_ATTR_VR_MESSAGE_PROTOCOL};
+use crate::netlink::{deserialize_attrs, NetlinkError, NetlinkMessage};
+use crate::vr_messages::Message;
+use std::time::Instant;
+
+/// Carries encoded sandesh requests to a vrouter and brings the replies back.
+///
+/// A transport only moves bytes, encoding the request and decoding the
+/// replies is left to `Message`. Code written against `&dyn Transport` can be
+/// pointed at the kernel module, the DPDK socket, an in-memory fake or a
+/// recording proxy.
+pub trait Transport {
+    /// Sends one encoded sandesh request and returns the sandesh payload of
+    /// every reply, in the order they came in.
+    fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError>;
+
+    /// Sends several encoded requests and returns the replies of each, in
+    /// the order of `payloads`. The outer error means the transport itself
+    /// failed and the outcome of the batch is unknown.
+    ///
+    /// The default sends the requests one by one.
+    fn request_batch(
+        &self,
+        payloads: &[Vec<u8>],
+    ) -> Result<BatchReplies, MessageHandleError> {
+        Ok(payloads.iter().map(|payload| self.request(payload)).collect())
+    }
+}
+
+/// The sandesh replies of every request in a batch.
+pub type BatchReplies = Vec<Result<Vec<Vec<u8>>, MessageHandleError>>;
+
+pub fn send_sandesh_msg_with(
+    transport: &dyn Transport,
+    payload: &Message,
+) -> Result<Vec<Message>, MessageHandleError> {
+    let request = payload.to_bytes()?;
+    let started = Instant::now();
+    let result = transport.request(&request).and_then(decode_replies);
+    notify(payload, request.len(), started.elapsed(), result.as_deref());
+    result
+}
+
+/// Sends `payloads` as a batch and decodes the replies of each request.
+///
+/// A request that fails, to encode or on the vrouter side, only fails its
+/// own entry of the result.
+pub fn send_sandesh_batch_with(
+    transport: &dyn Transport,
+    payloads: &[Message],
+) -> Result<Vec<Result<Vec<Message>, MessageHandleError>>, MessageHandleError> {
+    let mut results = Vec::with_capacity(payloads.len());
+    let mut requests = Vec::with_capacity(payloads.len());
+    for payload in payloads {
+        match payload.to_bytes() {
+            Ok(bytes) => {
+                requests.push(bytes);
+                results.push(None);
+            }
+            Err(err) => results.push(Some(Err(err.into()))),
+        }
+    }
+    let started = Instant::now();
+    let replies = transport.request_batch(&requests);
+    let latency = started.elapsed();
+    let mut replies = match replies {
+        Ok(replies) => replies.into_iter(),
+        Err(err) => {
+            let encoded = results.iter().zip(payloads).filter(|(r, _)| r.is_none());
+            for ((_, payload), request) in encoded.zip(&requests) {
+                notify(payload, request.len(), latency, Err(&err));
+            }
+            return Err(err);
+        }
+    };
+    let mut requests = requests.iter();
+    let results = results.into_iter().zip(payloads).map(|(result, payload)| {
+        let result = match result {
+            Some(err) => return err,
+            None => match replies.next() {
+                Some(Ok(replies)) => decode_replies(replies),
+                Some(Err(err)) => Err(err),
+                None => Err(MessageHandleError::MessageOutOfOrder),
+            },
+        };
+        let request_size = requests.next().map(Vec::len).unwrap_or(0);
+        notify(payload, request_size, latency, result.as_deref());
+        result
+    });
+    Ok(results.collect())
+}
+
+// private functions
+
+fn decode_replies(replies: Vec<Vec<u8>>) -> Result<Vec<Message>, MessageHandleError> {
+    let mut messages = Vec::new();
+    for reply in replies {
+        messages.extend(handle_sandesh_reply(&reply)?);
+    }
+    Ok(messages)
+}
+
+// Takes the sandesh payload out of each generic netlink reply, replies
+// without a NL_ATTR_VR_MESSAGE_PROTOCOL attribute carry nothing.
+pub(crate) fn sandesh_payloads(
+    replies: Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>,
+) -> Result<Vec<Vec<u8>>, NetlinkError> {
+    let mut payloads = Vec::new();
+    for nl_msg in replies {
+        for attr in deserialize_attrs(&nl_msg.payload.payload[..]) {
+            let (ty, value) = attr?;
+            if ty == NL_ATTR_VR_MESSAGE_PROTOCOL {
+                payloads.push(value.to_vec());
+                break;
+            }
+        }
+    }
+    Ok(payloads)
+}
@@ -0,0 +1,115 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::message::GenericNetlinkMessage;
+use super::retry::{send_sandesh_msg_with_retry, RetryPolicy};
+use super::transport::{sandesh_payloads, Transport};
+use super::MessageHandleError;
+use super::{GENL_START_ALLOC, NETLINK_VERSION, SANDESH_REQUEST};
+use super::NL_ATTR_VR_MESSAGE_PROTOCOL;
+use crate::netlink::{NetlinkAttr, NetlinkError, NetlinkMessage, NLM_F_REQUEST};
+use crate::vr_messages::Message;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+pub const DPDK_NETLINK_SOCKET_PATH: &str = "/var/run/vrouter/dpdk_netlink";
+
+/// The DPDK vrouter has no generic netlink controller to resolve a family
+/// from and echoes the request type back, so any fixed id does.
+pub const DPDK_NETLINK_FAMILY_ID: u16 = GENL_START_ALLOC;
+
+/// A session with a DPDK vrouter over its netlink Unix socket.
+///
+/// Messages are framed exactly like on the kernel generic netlink socket
+/// (`nlmsghdr`, `genlmsghdr` and a NL_ATTR_VR_MESSAGE_PROTOCOL attribute),
+/// only carried over a stream instead of datagrams.
+pub struct VrouterUnixSocket {
+    family_id: u16,
+    seq: AtomicU32,
+    retry: RetryPolicy,
+    stream: Mutex<UnixStream>,
+}
+
+impl VrouterUnixSocket {
+    pub fn new() -> Result<VrouterUnixSocket, MessageHandleError> {
+        VrouterUnixSocket::connect(DPDK_NETLINK_SOCKET_PATH)
+    }
+
+    pub fn connect<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<VrouterUnixSocket, MessageHandleError> {
+        let stream = UnixStream::connect(path).map_err(NetlinkError::from)?;
+        Ok(VrouterUnixSocket {
+            family_id: DPDK_NETLINK_FAMILY_ID,
+            seq: AtomicU32::new(1),
+            retry: RetryPolicy::never(),
+            stream: Mutex::new(stream),
+        })
+    }
+
+    /// Override the `nlmsg_type` stamped on requests.
+    pub fn with_family_id(mut self, family_id: u16) -> VrouterUnixSocket {
+        self.family_id = family_id;
+        self
+    }
+
+    pub fn family_id(&self) -> u16 { self.family_id }
+
+    /// Send requests the vrouter turned down again, as `policy` allows. By
+    /// default every request is sent once.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) { self.retry = policy; }
+
+    pub fn retry_policy(&self) -> &RetryPolicy { &self.retry }
+
+    /// Give up on a reply after `timeout`, with `MessageHandleError::Timeout`.
+    /// `None` waits forever, which is the default.
+    ///
+    /// The stream cannot be resynchronised after a reply timed out half way,
+    /// reconnect in that case.
+    pub fn set_recv_timeout(
+        &self,
+        timeout: Option<Duration>,
+    ) -> Result<(), MessageHandleError> {
+        let stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        stream.set_read_timeout(timeout).map_err(NetlinkError::from)?;
+        Ok(())
+    }
+
+    pub fn set_non_blocking(&self, non_blocking: bool) -> Result<(), MessageHandleError> {
+        let stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        stream.set_nonblocking(non_blocking).map_err(NetlinkError::from)?;
+        Ok(())
+    }
+
+    pub fn send(&self, payload: &Message) -> Result<Vec<Message>, MessageHandleError> {
+        send_sandesh_msg_with_retry(self, payload, &self.retry)
+    }
+
+    // private functions
+
+    fn round_trip(
+        &self,
+        payload: Vec<u8>,
+    ) -> Result<Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>, NetlinkError> {
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+        let nl_attr = &[NetlinkAttr::new(NL_ATTR_VR_MESSAGE_PROTOCOL, payload)] as &[_];
+        let nl_msg = NetlinkMessage::new(
+            self.family_id,
+            NLM_F_REQUEST,
+            GenericNetlinkMessage::new(SANDESH_REQUEST, NETLINK_VERSION, nl_attr),
+        )
+        .with_seq(seq, 0);
+        let mut stream = self.stream.lock().unwrap_or_else(|e| e.into_inner());
+        nl_msg.send_stream(&mut *stream)?;
+        nl_msg.recv_stream(&mut *stream)
+    }
+}
+
+impl Transport for VrouterUnixSocket {
+    fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+        Ok(sandesh_payloads(self.round_trip(payload.to_vec())?)?)
+    }
+}
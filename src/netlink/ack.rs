@@ -0,0 +1,73 @@
+use super::attr::{find_attr, parse_attrs, Attr, AttrKind};
+use super::error::InvalidBuffer;
+use super::raw::{nlmsgerr, NLMSG_ALIGN, NLMSG_HDRLEN, NLM_F_ACK_TLVS, NLM_F_CAPPED};
+use super::raw::{NLMSGERR_ATTR_MSG, NLMSGERR_ATTR_OFFS};
+use std::fmt;
+use std::io;
+use std::mem::size_of;
+use zerocopy::LayoutVerified;
+
+/// Decoded payload of an NLMSG_ERROR message.
+///
+/// `errno` is zero for a plain acknowledgement. `offset` and `message` are
+/// only present when the kernel attached extended ACK attributes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NetlinkAck {
+    pub errno: i32,
+    pub offset: Option<u32>,
+    pub message: Option<String>,
+}
+
+impl NetlinkAck {
+    pub fn deserialize(flags: u16, buf: &[u8]) -> Result<NetlinkAck, InvalidBuffer> {
+        let header_len = size_of::<nlmsgerr>();
+        if buf.len() < header_len {
+            return Err(InvalidBuffer::ErrorHeader(buf.len()));
+        }
+        let (header, rest) = buf.split_at(header_len);
+        let header = LayoutVerified::<_, nlmsgerr>::new(header)
+            .ok_or(InvalidBuffer::ErrorHeader(buf.len()))?;
+        let mut ack = NetlinkAck {
+            errno: -header.error,
+            offset: None,
+            message: None,
+        };
+        if flags & NLM_F_ACK_TLVS == 0 {
+            return Ok(ack);
+        }
+        // Unless capped, the kernel echoes the whole request before the TLVs
+        let attrs = if flags & NLM_F_CAPPED != 0 {
+            rest
+        } else {
+            let echo_len = NLMSG_ALIGN(header.msg.nlmsg_len).saturating_sub(NLMSG_HDRLEN);
+            &rest[(echo_len as usize).min(rest.len())..]
+        };
+        let attrs = parse_attrs(attrs, EXT_ACK_POLICY)?;
+        let message = find_attr(&attrs, NLMSGERR_ATTR_MSG as u16).and_then(Attr::as_str);
+        ack.message = message.map(str::to_string);
+        ack.offset = find_attr(&attrs, NLMSGERR_ATTR_OFFS as u16).and_then(Attr::as_u32);
+        Ok(ack)
+    }
+
+    pub fn is_ack(&self) -> bool { self.errno == 0 }
+}
+
+impl fmt::Display for NetlinkAck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", io::Error::from_raw_os_error(self.errno))?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " (attribute at offset {})", offset)?;
+        }
+        Ok(())
+    }
+}
+
+// private functions
+
+const EXT_ACK_POLICY: &[(u16, AttrKind)] = &[
+    (NLMSGERR_ATTR_MSG as u16, AttrKind::String),
+    (NLMSGERR_ATTR_OFFS as u16, AttrKind::U32),
+];
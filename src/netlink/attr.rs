@@ -1,5 +1,6 @@
+use super::deserialize::*;
 use super::error::InvalidBuffer;
-use super::raw::{nlattr, NLA_ALIGN};
+use super::raw::{nlattr, NLA_ALIGN, NLA_F_NESTED, NLA_TYPE_MASK};
 use super::Serialize;
 use std::mem::size_of;
 use zerocopy::LayoutVerified;
@@ -54,16 +55,19 @@ impl<'a> Iterator for AttrsIter<'a> {
             return Some(Err(InvalidBuffer::AttrHeader(self.buf.len())));
         }
         let (attr, payload) = self.buf.split_at(attr_len);
-        let attr = LayoutVerified::<_, nlattr>::new(attr).unwrap();
+        let attr = match LayoutVerified::<_, nlattr>::new(attr) {
+            Some(attr) if attr.nla_len as usize >= attr_len => attr,
+            _ => return Some(Err(InvalidBuffer::AttrHeader(self.buf.len()))),
+        };
         let aligned_attr_len = NLA_ALIGN(attr.nla_len) as usize;
-        if self.buf.len() < aligned_attr_len {
+        if self.buf.len() < attr.nla_len as usize {
             return Some(Err(InvalidBuffer::AttrPayload(
                 aligned_attr_len,
                 self.buf.len(),
             )));
         }
         let payload_len = attr.nla_len as usize - attr_len;
-        self.buf = &self.buf[aligned_attr_len..];
+        self.buf = &self.buf[aligned_attr_len.min(self.buf.len())..];
         Some(Ok((attr.nla_type, &payload[..payload_len])))
     }
 }
@@ -76,3 +80,239 @@ impl<'a> AttrsIter<'a> {
         self.next().transpose()?.ok_or(err)
     }
 }
+
+/// A typed attribute, possibly nesting further attributes.
+///
+/// This is what `parse_attrs` yields, and it can be serialized back, e.g.
+/// `Attr::nested(ty, vec![Attr::u32(1, 10), Attr::string(2, "vhost0")])`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Attr {
+    pub ty: u16,
+    pub value: AttrValue,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AttrValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I32(i32),
+    /// Serialized with a NUL terminator
+    String(String),
+    Binary(Vec<u8>),
+    /// A zero-length attribute whose presence is the value
+    Flag,
+    /// Serialized with NLA_F_NESTED set
+    Nested(Vec<Attr>),
+}
+
+/// How `parse_attrs` decodes the payload of an attribute type.
+#[derive(Debug, Clone, Copy)]
+pub enum AttrKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    I32,
+    String,
+    Binary,
+    Flag,
+    /// Attributes described by the given policy
+    Nested(&'static [(u16, AttrKind)]),
+    /// Entries indexed from 1, each nesting attributes of the given policy
+    Array(&'static [(u16, AttrKind)]),
+}
+
+impl Attr {
+    pub fn new(ty: u16, value: AttrValue) -> Attr { Attr { ty, value } }
+
+    pub fn u8(ty: u16, value: u8) -> Attr { Attr::new(ty, AttrValue::U8(value)) }
+
+    pub fn u16(ty: u16, value: u16) -> Attr { Attr::new(ty, AttrValue::U16(value)) }
+
+    pub fn u32(ty: u16, value: u32) -> Attr { Attr::new(ty, AttrValue::U32(value)) }
+
+    pub fn u64(ty: u16, value: u64) -> Attr { Attr::new(ty, AttrValue::U64(value)) }
+
+    pub fn i32(ty: u16, value: i32) -> Attr { Attr::new(ty, AttrValue::I32(value)) }
+
+    pub fn string(ty: u16, value: &str) -> Attr {
+        Attr::new(ty, AttrValue::String(value.to_string()))
+    }
+
+    pub fn binary(ty: u16, value: Vec<u8>) -> Attr {
+        Attr::new(ty, AttrValue::Binary(value))
+    }
+
+    pub fn flag(ty: u16) -> Attr { Attr::new(ty, AttrValue::Flag) }
+
+    pub fn nested(ty: u16, attrs: Vec<Attr>) -> Attr {
+        Attr::new(ty, AttrValue::Nested(attrs))
+    }
+
+    pub fn as_u8(&self) -> Option<u8> {
+        match self.value {
+            AttrValue::U8(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u16(&self) -> Option<u16> {
+        match self.value {
+            AttrValue::U16(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        match self.value {
+            AttrValue::U32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.value {
+            AttrValue::U64(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self.value {
+            AttrValue::I32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            AttrValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            AttrValue::Binary(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The nested attributes, empty unless this is a nested attribute.
+    pub fn children(&self) -> &[Attr] {
+        match &self.value {
+            AttrValue::Nested(attrs) => attrs,
+            _ => &[],
+        }
+    }
+
+    /// The first nested attribute of type `ty`.
+    pub fn get(&self, ty: u16) -> Option<&Attr> { find_attr(self.children(), ty) }
+
+    // private functions
+
+    fn payload_len(&self) -> usize {
+        match &self.value {
+            AttrValue::U8(_) => 1,
+            AttrValue::U16(_) => 2,
+            AttrValue::U32(_) | AttrValue::I32(_) => 4,
+            AttrValue::U64(_) => 8,
+            AttrValue::String(value) => value.len() + 1,
+            AttrValue::Binary(value) => value.len(),
+            AttrValue::Flag => 0,
+            AttrValue::Nested(attrs) => attrs.iter().map(|a| a.len() as usize).sum(),
+        }
+    }
+}
+
+impl Serialize for Attr {
+    fn len(&self) -> u32 {
+        NLA_ALIGN((size_of::<nlattr>() + self.payload_len()) as u16) as u32
+    }
+
+    fn serialize(&self, buf: &mut [u8]) {
+        let attr_len = size_of::<nlattr>();
+        let payload_len = self.payload_len();
+        let (attr, payload) = buf.split_at_mut(attr_len);
+        let mut attr = LayoutVerified::<_, nlattr>::new(attr).expect("invalid buffer");
+        attr.nla_len = (attr_len + payload_len) as u16;
+        attr.nla_type = match self.value {
+            AttrValue::Nested(_) => self.ty | NLA_F_NESTED,
+            _ => self.ty,
+        };
+        let (payload, padding) = payload.split_at_mut(payload_len);
+        match &self.value {
+            AttrValue::U8(value) => payload.copy_from_slice(&value.to_ne_bytes()),
+            AttrValue::U16(value) => payload.copy_from_slice(&value.to_ne_bytes()),
+            AttrValue::U32(value) => payload.copy_from_slice(&value.to_ne_bytes()),
+            AttrValue::U64(value) => payload.copy_from_slice(&value.to_ne_bytes()),
+            AttrValue::I32(value) => payload.copy_from_slice(&value.to_ne_bytes()),
+            AttrValue::String(value) => {
+                let (string, nul) = payload.split_at_mut(value.len());
+                string.copy_from_slice(value.as_bytes());
+                nul[0] = 0;
+            }
+            AttrValue::Binary(value) => payload.copy_from_slice(&value[..]),
+            AttrValue::Flag => (),
+            AttrValue::Nested(attrs) => (&attrs[..]).serialize(payload),
+        }
+        padding.iter_mut().for_each(|b| *b = 0);
+    }
+}
+
+/// Decodes a run of attributes into a typed tree.
+///
+/// Types missing from `policy` are kept as `AttrValue::Binary`, or as nested
+/// attributes when the sender set NLA_F_NESTED.
+pub fn parse_attrs(
+    buf: &[u8],
+    policy: &[(u16, AttrKind)],
+) -> Result<Vec<Attr>, InvalidBuffer> {
+    let mut attrs = Vec::new();
+    for attr in deserialize_attrs(buf) {
+        let (ty, value) = attr?;
+        let kind = policy
+            .iter()
+            .find(|(policy_ty, _)| *policy_ty == ty & NLA_TYPE_MASK)
+            .map(|(_, kind)| *kind);
+        let kind = match kind {
+            Some(kind) => kind,
+            None if ty & NLA_F_NESTED != 0 => AttrKind::Nested(&[]),
+            None => AttrKind::Binary,
+        };
+        attrs.push(Attr::new(ty & NLA_TYPE_MASK, parse_value(value, kind)?));
+    }
+    Ok(attrs)
+}
+
+/// The first attribute of type `ty`.
+pub fn find_attr(attrs: &[Attr], ty: u16) -> Option<&Attr> {
+    attrs.iter().find(|attr| attr.ty == ty)
+}
+
+// private functions
+
+fn parse_value(buf: &[u8], kind: AttrKind) -> Result<AttrValue, InvalidBuffer> {
+    Ok(match kind {
+        AttrKind::U8 => AttrValue::U8(deserialize_u8(buf)?),
+        AttrKind::U16 => AttrValue::U16(deserialize_u16(buf)?),
+        AttrKind::U32 => AttrValue::U32(deserialize_u32(buf)?),
+        AttrKind::U64 => AttrValue::U64(deserialize_u64(buf)?),
+        AttrKind::I32 => AttrValue::I32(deserialize_i32(buf)?),
+        AttrKind::String => AttrValue::String(deserialize_string(buf)?),
+        AttrKind::Binary => AttrValue::Binary(buf.to_vec()),
+        AttrKind::Flag => AttrValue::Flag,
+        AttrKind::Nested(policy) => AttrValue::Nested(parse_attrs(buf, policy)?),
+        AttrKind::Array(policy) => {
+            let mut entries = Vec::new();
+            for entry in deserialize_attrs(buf) {
+                let (index, entry) = entry?;
+                let entry = AttrValue::Nested(parse_attrs(entry, policy)?);
+                entries.push(Attr::new(index & NLA_TYPE_MASK, entry));
+            }
+            AttrValue::Nested(entries)
+        }
+    })
+}
@@ -12,3 +12,27 @@ pub fn deserialize_u32(buf: &[u8]) -> Result<u32, InvalidBuffer> {
         .map(u32::from_ne_bytes)
         .map_err(|_| InvalidBuffer::U32(buf.len()))
 }
+
+pub fn deserialize_u8(buf: &[u8]) -> Result<u8, InvalidBuffer> {
+    buf.try_into()
+        .map(u8::from_ne_bytes)
+        .map_err(|_| InvalidBuffer::U8(buf.len()))
+}
+
+pub fn deserialize_u64(buf: &[u8]) -> Result<u64, InvalidBuffer> {
+    buf.try_into()
+        .map(u64::from_ne_bytes)
+        .map_err(|_| InvalidBuffer::U64(buf.len()))
+}
+
+pub fn deserialize_i32(buf: &[u8]) -> Result<i32, InvalidBuffer> {
+    buf.try_into()
+        .map(i32::from_ne_bytes)
+        .map_err(|_| InvalidBuffer::I32(buf.len()))
+}
+
+/// Reads a NUL terminated string attribute, a missing terminator is tolerated.
+pub fn deserialize_string(buf: &[u8]) -> Result<String, InvalidBuffer> {
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    String::from_utf8(buf[..len].to_vec()).map_err(|_| InvalidBuffer::String(len))
+}
@@ -1,3 +1,5 @@
+use super::ack::NetlinkAck;
+use crate::genetlink::message::InvalidBuffer as GenericInvalidBuffer;
 use std::io;
 use thiserror::Error;
 
@@ -7,16 +9,55 @@ pub enum NetlinkError {
     Io(#[from] io::Error),
     #[error("invalid netlink message")]
     Netlink(#[from] InvalidBuffer),
+    #[error("invalid generic netlink message")]
+    GenericNetlink(#[from] GenericInvalidBuffer),
+    #[error("unexpected reply {seq}/{pid}, expected {expected_seq}/{expected_pid}")]
+    UnexpectedReply {
+        seq: u32,
+        pid: u32,
+        expected_seq: u32,
+        expected_pid: u32,
+    },
+    #[error("the kernel rejected the request: {0}")]
+    Kernel(NetlinkAck),
+    #[error("invalid capture")]
+    Capture(#[from] InvalidCapture),
+    #[error("generic netlink family {0} not found")]
+    FamilyNotFound(String),
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum InvalidBuffer {
     #[error("insufficient buffer for attr header, got buffer size {0}")]
     AttrHeader(usize),
     #[error("incomplete payload, expect buffer size {0} got {1}")]
     AttrPayload(usize, usize),
+    #[error("insufficient buffer for netlink message header, got buffer size {0}")]
+    MessageHeader(usize),
+    #[error("incomplete netlink message, expect buffer size {0} got {1}")]
+    MessagePayload(usize, usize),
+    #[error("insufficient buffer for netlink error header, got buffer size {0}")]
+    ErrorHeader(usize),
+    #[error("expect u8, got buffer size {0}")]
+    U8(usize),
     #[error("expect u16, got buffer size {0}")]
     U16(usize),
     #[error("expect u32, got buffer size {0}")]
     U32(usize),
+    #[error("expect u64, got buffer size {0}")]
+    U64(usize),
+    #[error("expect i32, got buffer size {0}")]
+    I32(usize),
+    #[error("expect utf-8 string, got invalid buffer size {0}")]
+    String(usize),
+}
+
+#[derive(Debug, Error)]
+pub enum InvalidCapture {
+    #[error("not a pcap file, got magic {0:#010x}")]
+    Magic(u32),
+    #[error("unsupported link type {0}, expect LINKTYPE_NETLINK")]
+    LinkType(u32),
+    #[error("truncated capture at offset {0}")]
+    Truncated(usize),
 }
@@ -1,10 +1,19 @@
-use super::raw::{nlmsghdr, NLMSG_LENGTH};
+use super::ack::NetlinkAck;
+use super::error::{InvalidBuffer, NetlinkError};
+use super::pcap::{capture, Direction};
+use super::raw::{nlmsghdr, NLMSG_ALIGN, NLMSG_ALIGNTO, NLMSG_DONE, NLMSG_ERROR};
+use super::raw::{NLMSG_LENGTH, NLMSG_NOOP, NLM_F_ACK, NLM_F_MULTI};
 use super::Serialize;
 use crate::genetlink::GenericNetlinkMessage;
+use libc::{MSG_PEEK, MSG_TRUNC};
 use netlink_sys::Socket;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
 use std::process;
 use zerocopy::LayoutVerified;
 
+type Replies = Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>;
+
 #[derive(Debug)]
 pub struct NetlinkMessage<P> {
     pub ty: u16,
@@ -25,28 +34,151 @@ impl<P: Serialize> NetlinkMessage<P> {
         }
     }
 
-    pub fn send_nl(&self, socket: &Socket) {
-        let mut buffer = [0; 1000];
-        let byte_size = self.len() as usize;
-        self.serialize(&mut buffer[..byte_size]);
-        socket.send(&buffer[..byte_size], 0).unwrap();
+    pub fn with_seq(mut self, seq: u32, pid: u32) -> NetlinkMessage<P> {
+        self.seq = seq;
+        self.pid = pid;
+        self
+    }
+
+    pub fn send_nl(&self, socket: &Socket) -> Result<(), NetlinkError> {
+        let mut buffer = vec![0; self.len() as usize];
+        self.serialize(&mut buffer[..]);
+        socket.send(&buffer[..], 0)?;
+        capture(Direction::Outgoing, &buffer[..]);
+        Ok(())
     }
 
     // Generic NETLINK message specfic shortcut fucntion
-    pub fn recv_nl(socket: &Socket) -> NetlinkMessage<GenericNetlinkMessage<Vec<u8>>> {
-        let mut buffer = [0; 1000];
-        let reply_len = socket.recv(&mut buffer, 0).unwrap();
-        let nl_msg = NetlinkMessage::deserialize(&buffer[..reply_len]);
-        let genl_msg = GenericNetlinkMessage::deserialize(nl_msg.payload).unwrap();
-        NetlinkMessage::new(
-            nl_msg.ty,
-            nl_msg.flags,
-            GenericNetlinkMessage::new(
+    //
+    // Reads datagrams until the reply to this request is complete: a single
+    // datagram for a plain reply, everything up to NLMSG_DONE for a
+    // NLM_F_MULTI one, or up to the acknowledgement when NLM_F_ACK was set.
+    // Leftover replies to earlier requests are discarded, anything else is
+    // reported as unexpected. NLMSG_ERROR replies are returned as errors.
+    pub fn recv_nl(
+        &self,
+        socket: &Socket,
+    ) -> Result<Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>, NetlinkError> {
+        self.collect_replies(|| recv_datagram(socket))
+    }
+
+    // recv_nl, reading the datagrams from `recv`
+    fn collect_replies<F>(&self, mut recv: F) -> Result<Replies, NetlinkError>
+    where
+        F: FnMut() -> io::Result<Vec<u8>>,
+    {
+        let (seq, pid) = (self.seq, self.pid);
+        let mut collector = ReplyCollector::new(self.flags);
+        loop {
+            let buffer = recv()?;
+            for nl_msg in deserialize_messages(&buffer[..]) {
+                let nl_msg = nl_msg?;
+                if nl_msg.seq != seq || nl_msg.pid != pid {
+                    if nl_msg.pid == pid && is_stale_seq(nl_msg.seq, seq) {
+                        continue;
+                    }
+                    return Err(NetlinkError::UnexpectedReply {
+                        seq: nl_msg.seq,
+                        pid: nl_msg.pid,
+                        expected_seq: seq,
+                        expected_pid: pid,
+                    });
+                }
+                if collector.push(&nl_msg)? {
+                    return Ok(collector.into_messages());
+                }
+            }
+        }
+    }
+
+    pub fn send_stream<W: Write>(&self, stream: &mut W) -> Result<(), NetlinkError> {
+        let mut buffer = vec![0; self.len() as usize];
+        self.serialize(&mut buffer[..]);
+        stream.write_all(&buffer[..])?;
+        capture(Direction::Outgoing, &buffer[..]);
+        Ok(())
+    }
+
+    // Same as recv_nl, for netlink messages carried over a byte stream such
+    // as the Unix socket of the DPDK vrouter. The peer has no port id of its
+    // own, so replies are matched by sequence number only.
+    pub fn recv_stream<R: Read>(
+        &self,
+        stream: &mut R,
+    ) -> Result<Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>, NetlinkError> {
+        let mut collector = ReplyCollector::new(self.flags);
+        loop {
+            let buffer = recv_stream_message(stream)?;
+            let nl_msg = NetlinkMessage::deserialize(&buffer[..])?;
+            if nl_msg.seq != self.seq {
+                if is_stale_seq(nl_msg.seq, self.seq) {
+                    continue;
+                }
+                return Err(NetlinkError::UnexpectedReply {
+                    seq: nl_msg.seq,
+                    pid: nl_msg.pid,
+                    expected_seq: self.seq,
+                    expected_pid: nl_msg.pid,
+                });
+            }
+            if collector.push(&nl_msg)? {
+                return Ok(collector.into_messages());
+            }
+        }
+    }
+}
+
+/// Accumulates the messages answering a single request.
+///
+/// The caller feeds every message carrying the request's sequence number
+/// and port id; `push` tells when the reply is complete.
+#[derive(Debug)]
+pub struct ReplyCollector {
+    want_ack: bool,
+    messages: Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>>,
+}
+
+impl ReplyCollector {
+    /// `flags` are the `nlmsg_flags` of the request.
+    pub fn new(flags: u16) -> ReplyCollector {
+        ReplyCollector {
+            want_ack: flags & NLM_F_ACK != 0,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Returns `Ok(true)` once the reply is complete, and the kernel error
+    /// when the request has been rejected.
+    pub fn push(&mut self, nl_msg: &NetlinkMessage<&[u8]>) -> Result<bool, NetlinkError> {
+        match nl_msg.ty as u32 {
+            NLMSG_DONE => return Ok(true),
+            NLMSG_NOOP => return Ok(false),
+            NLMSG_ERROR => {
+                let ack = NetlinkAck::deserialize(nl_msg.flags, nl_msg.payload)?;
+                if !ack.is_ack() {
+                    return Err(NetlinkError::Kernel(ack));
+                }
+                return Ok(true);
+            }
+            _ => (),
+        }
+        let genl_msg = GenericNetlinkMessage::deserialize(nl_msg.payload)?;
+        self.messages.push(NetlinkMessage {
+            ty: nl_msg.ty,
+            flags: nl_msg.flags,
+            seq: nl_msg.seq,
+            pid: nl_msg.pid,
+            payload: GenericNetlinkMessage::new(
                 genl_msg.cmd,
                 genl_msg.version,
                 genl_msg.payload.to_vec(),
             ),
-        )
+        });
+        Ok(nl_msg.flags & NLM_F_MULTI == 0 && !self.want_ack)
+    }
+
+    pub fn into_messages(self) -> Vec<NetlinkMessage<GenericNetlinkMessage<Vec<u8>>>> {
+        self.messages
     }
 }
 
@@ -68,16 +200,243 @@ impl<P: Serialize> Serialize for NetlinkMessage<P> {
 }
 
 impl<'a> NetlinkMessage<&'a [u8]> {
-    pub fn deserialize(buf: &'a [u8]) -> Self {
+    pub fn deserialize(buf: &'a [u8]) -> Result<Self, InvalidBuffer> {
         let header_len = NLMSG_LENGTH(0) as usize;
-        let (header, payload) = buf.split_at(header_len);
-        let header = LayoutVerified::<_, nlmsghdr>::new(header).expect("invalid buffer");
-        Self {
+        if buf.len() < header_len {
+            return Err(InvalidBuffer::MessageHeader(buf.len()));
+        }
+        let (header, _) = buf.split_at(header_len);
+        let header = LayoutVerified::<_, nlmsghdr>::new(header)
+            .ok_or(InvalidBuffer::MessageHeader(buf.len()))?;
+        let msg_len = header.nlmsg_len as usize;
+        if msg_len < header_len || buf.len() < msg_len {
+            return Err(InvalidBuffer::MessagePayload(msg_len, buf.len()));
+        }
+        Ok(Self {
             ty: header.nlmsg_type,
             flags: header.nlmsg_flags,
             seq: header.nlmsg_seq,
             pid: header.nlmsg_pid,
-            payload,
+            payload: &buf[header_len..msg_len],
+        })
+    }
+}
+
+pub fn deserialize_messages(buf: &[u8]) -> MessagesIter<'_> { MessagesIter { buf } }
+
+/// Walks the `nlmsghdr`s packed into a single datagram.
+pub struct MessagesIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for MessagesIter<'a> {
+    type Item = Result<NetlinkMessage<&'a [u8]>, InvalidBuffer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        match NetlinkMessage::deserialize(self.buf) {
+            Err(e) => {
+                self.buf = &[];
+                Some(Err(e))
+            }
+            Ok(nl_msg) => {
+                let msg_len = NLMSG_LENGTH(nl_msg.payload.len() as u32);
+                let aligned_len = NLMSG_ALIGN(msg_len) as usize;
+                self.buf = &self.buf[aligned_len.min(self.buf.len())..];
+                Some(Ok(nl_msg))
+            }
+        }
+    }
+}
+
+/// Packs several requests into a single datagram, one `nlmsghdr` each.
+pub fn send_batch_nl<P: Serialize>(
+    messages: &[NetlinkMessage<P>],
+    socket: &Socket,
+) -> Result<(), NetlinkError> {
+    let len = messages.iter().map(|m| NLMSG_ALIGN(m.len()) as usize).sum();
+    let mut buffer = vec![0; len];
+    let mut offset = 0;
+    for nl_msg in messages {
+        let msg_len = nl_msg.len() as usize;
+        nl_msg.serialize(&mut buffer[offset..offset + msg_len]);
+        offset += NLMSG_ALIGN(msg_len as u32) as usize;
+    }
+    socket.send(&buffer[..], 0)?;
+    capture(Direction::Outgoing, &buffer[..]);
+    Ok(())
+}
+
+/// Reads the replies to requests sent together by `send_batch_nl`.
+///
+/// Each request completes on its own, so a rejected request does not fail
+/// the rest of the batch: the outer error is only for the socket itself or
+/// a reply that cannot be attributed, the inner ones follow `recv_nl`.
+pub fn recv_batch_nl<P: Serialize>(
+    requests: &[NetlinkMessage<P>],
+    socket: &Socket,
+) -> Result<Vec<Result<Replies, NetlinkError>>, NetlinkError> {
+    let (first_seq, pid) = match requests.first() {
+        Some(nl_msg) => (nl_msg.seq, nl_msg.pid),
+        None => return Ok(vec![]),
+    };
+    let index: HashMap<u32, usize> =
+        requests.iter().enumerate().map(|(i, nl_msg)| (nl_msg.seq, i)).collect();
+    let mut collectors: Vec<_> =
+        requests.iter().map(|nl_msg| Some(ReplyCollector::new(nl_msg.flags))).collect();
+    let mut replies: Vec<_> = requests.iter().map(|_| None).collect();
+    let mut outstanding = requests.len();
+    while outstanding > 0 {
+        let buffer = recv_datagram(socket)?;
+        for nl_msg in deserialize_messages(&buffer[..]) {
+            let nl_msg = nl_msg?;
+            let i = match index.get(&nl_msg.seq) {
+                Some(&i) if nl_msg.pid == pid => i,
+                _ if nl_msg.pid == pid && is_stale_seq(nl_msg.seq, first_seq) => continue,
+                _ => {
+                    return Err(NetlinkError::UnexpectedReply {
+                        seq: nl_msg.seq,
+                        pid: nl_msg.pid,
+                        expected_seq: first_seq,
+                        expected_pid: pid,
+                    })
+                }
+            };
+            let collector = match collectors[i].as_mut() {
+                Some(collector) => collector,
+                None => continue,
+            };
+            let reply = match collector.push(&nl_msg) {
+                Ok(false) => continue,
+                Ok(true) => collectors[i].take().map(|c| Ok(c.into_messages())),
+                Err(err) => {
+                    collectors[i] = None;
+                    Some(Err(err))
+                }
+            };
+            replies[i] = reply;
+            outstanding -= 1;
+        }
+    }
+    Ok(replies.into_iter().flatten().collect())
+}
+
+// Peeks the pending datagram with MSG_TRUNC to learn its real size, then
+// reads it into a buffer of exactly that size.
+pub(crate) fn recv_datagram(socket: &Socket) -> io::Result<Vec<u8>> {
+    let datagram_len = socket.recv(&mut [0u8; 0], MSG_PEEK | MSG_TRUNC)?;
+    let mut buffer = vec![0; datagram_len];
+    let recv_len = socket.recv(&mut buffer[..], 0)?;
+    buffer.truncate(recv_len);
+    capture(Direction::Incoming, &buffer[..]);
+    Ok(buffer)
+}
+
+// private functions
+
+// Reads the header to learn the message length, then the rest of it and
+// the padding up to the next NLMSG_ALIGN boundary.
+fn recv_stream_message<R: Read>(stream: &mut R) -> Result<Vec<u8>, NetlinkError> {
+    let header_len = NLMSG_LENGTH(0) as usize;
+    let mut buffer = vec![0; header_len];
+    stream.read_exact(&mut buffer[..])?;
+    let msg_len = LayoutVerified::<_, nlmsghdr>::new(&buffer[..])
+        .ok_or(InvalidBuffer::MessageHeader(header_len))?
+        .nlmsg_len as usize;
+    if msg_len < header_len {
+        return Err(InvalidBuffer::MessagePayload(msg_len, header_len).into());
+    }
+    buffer.resize(msg_len, 0);
+    stream.read_exact(&mut buffer[header_len..])?;
+    let mut padding = [0; NLMSG_ALIGNTO as usize];
+    let padding_len = NLMSG_ALIGN(msg_len as u32) as usize - msg_len;
+    stream.read_exact(&mut padding[..padding_len])?;
+    capture(Direction::Incoming, &buffer[..]);
+    Ok(buffer)
+}
+
+// Sequence numbers wrap around, so "earlier" means within half the space.
+fn is_stale_seq(seq: u32, expected: u32) -> bool {
+    let distance = expected.wrapping_sub(seq);
+    distance != 0 && distance < u32::MAX / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlink::raw::NLM_F_REQUEST;
+
+    const SEQ: u32 = 5;
+    const PID: u32 = 100;
+
+    fn request(flags: u16) -> NetlinkMessage<GenericNetlinkMessage<Vec<u8>>> {
+        let genl_msg = GenericNetlinkMessage::new(1, 2, vec![]);
+        NetlinkMessage::new(0x20, NLM_F_REQUEST | flags, genl_msg).with_seq(SEQ, PID)
+    }
+
+    fn encode(ty: u16, flags: u16, seq: u32, pid: u32, payload: Vec<u8>) -> Vec<u8> {
+        let genl_msg = GenericNetlinkMessage::new(1, 2, payload);
+        let nl_msg = NetlinkMessage::new(ty, flags, genl_msg).with_seq(seq, pid);
+        let mut buf = vec![0; NLMSG_ALIGN(nl_msg.len()) as usize];
+        let len = nl_msg.len() as usize;
+        nl_msg.serialize(&mut buf[..len]);
+        buf
+    }
+
+    // Hands out `datagrams` one at a time, then fails like a drained socket
+    fn recv_from(datagrams: Vec<Vec<u8>>) -> impl FnMut() -> io::Result<Vec<u8>> {
+        let mut datagrams = datagrams.into_iter();
+        move || datagrams.next().ok_or_else(|| io::ErrorKind::WouldBlock.into())
+    }
+
+    #[test]
+    fn multipart_reply_test() {
+        let mut first = encode(0x20, 0, SEQ - 1, PID, vec![9]);
+        first.extend(encode(0x20, NLM_F_MULTI, SEQ, PID, vec![1]));
+        let mut second = encode(0x20, NLM_F_MULTI, SEQ, PID, vec![2, 3]);
+        second.extend(encode(NLMSG_DONE as u16, NLM_F_MULTI, SEQ, PID, vec![]));
+        let datagrams = vec![first, second, encode(0x20, 0, SEQ, PID, vec![4])];
+
+        let replies = request(0).collect_replies(recv_from(datagrams)).unwrap();
+        // The reply to the stale request is dropped, NLMSG_DONE ends the reply
+        let payloads: Vec<_> = replies.iter().map(|m| &m.payload.payload[..]).collect();
+        assert_eq!(payloads, vec![&[1][..], &[2, 3][..]]);
+        assert!(replies.iter().all(|m| m.seq == SEQ && m.pid == PID));
+    }
+
+    #[test]
+    fn stale_reply_test() {
+        let mut datagram = encode(0x20, 0, SEQ - 2, PID, vec![7]);
+        datagram.extend(encode(0x20, 0, SEQ - 1, PID, vec![8]));
+        datagram.extend(encode(0x20, 0, SEQ, PID, vec![1]));
+
+        let replies = request(0).collect_replies(recv_from(vec![datagram])).unwrap();
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].payload.payload, vec![1]);
+    }
+
+    #[test]
+    fn foreign_pid_test() {
+        let datagram = encode(0x20, 0, SEQ, PID + 1, vec![1]);
+        match request(0).collect_replies(recv_from(vec![datagram])) {
+            Err(NetlinkError::UnexpectedReply {
+                seq: SEQ,
+                pid,
+                expected_seq: SEQ,
+                expected_pid: PID,
+            }) if pid == PID + 1 => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn future_seq_test() {
+        let datagram = encode(0x20, 0, SEQ + 1, PID, vec![1]);
+        match request(0).collect_replies(recv_from(vec![datagram])) {
+            Err(NetlinkError::UnexpectedReply { seq, .. }) if seq == SEQ + 1 => (),
+            res => panic!("unexpected result: {:?}", res),
         }
     }
 }
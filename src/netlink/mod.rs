@@ -1,13 +1,24 @@
+pub mod ack;
 pub mod attr;
 pub mod deserialize;
 pub mod error;
 pub mod message;
+pub mod netns;
+pub mod pcap;
 pub mod raw;
 pub mod serialize;
+pub mod sockopt;
 
+pub use self::ack::NetlinkAck;
 pub use self::attr::{deserialize_attrs, AttrsIter, NetlinkAttr};
+pub use self::attr::{find_attr, parse_attrs, Attr, AttrKind, AttrValue};
 pub use self::deserialize::*;
-pub use self::error::{InvalidBuffer, NetlinkError};
-pub use self::message::NetlinkMessage;
+pub use self::error::{InvalidBuffer, InvalidCapture, NetlinkError};
+pub use self::message::{deserialize_messages, MessagesIter};
+pub use self::message::{recv_batch_nl, send_batch_nl};
+pub use self::message::{NetlinkMessage, ReplyCollector};
+pub use self::netns::{in_netns, NetNs};
+pub use self::pcap::{start_capture, start_capture_to, stop_capture};
 pub use self::raw::*;
 pub use self::serialize::Serialize;
+pub use self::sockopt::{set_ext_ack, set_recv_buffer_size, set_recv_timeout};
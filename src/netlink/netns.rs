@@ -0,0 +1,54 @@
+use libc::CLONE_NEWNET;
+use std::fs::File;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+use std::thread;
+
+/// A network namespace to open netlink sockets in.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NetNs {
+    /// A namespace file such as `/var/run/netns/<name>`
+    Path(PathBuf),
+    /// The namespace the process `pid` runs in
+    Pid(u32),
+}
+
+impl NetNs {
+    /// A namespace created by `ip netns add <name>`.
+    pub fn named(name: &str) -> NetNs {
+        NetNs::Path(PathBuf::from("/var/run/netns").join(name))
+    }
+
+    pub fn path(&self) -> PathBuf {
+        match self {
+            NetNs::Path(path) => path.clone(),
+            NetNs::Pid(pid) => PathBuf::from(format!("/proc/{}/ns/net", pid)),
+        }
+    }
+}
+
+/// Runs `f` on a dedicated thread that has joined `netns`.
+///
+/// A socket keeps the namespace it was created in, so sockets created by `f`
+/// talk to `netns` from any thread. The namespace of the calling thread is
+/// left untouched.
+pub fn in_netns<F, T>(netns: &NetNs, f: F) -> io::Result<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let ns_file = File::open(netns.path())?;
+    let worker = thread::Builder::new()
+        .name("vr-netns".to_string())
+        .spawn(move || {
+            if unsafe { libc::setns(ns_file.as_raw_fd(), CLONE_NEWNET) } < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(f())
+        })?;
+    match worker.join() {
+        Ok(result) => result,
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
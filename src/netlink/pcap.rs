@@ -0,0 +1,233 @@
+use super::error::InvalidCapture;
+use super::raw::NETLINK_GENERIC;
+use once_cell::sync::Lazy;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Link type of frames captured on an `nlmon` interface.
+pub const LINKTYPE_NETLINK: u32 = 253;
+pub const ARPHRD_NETLINK: u16 = 824;
+pub const PCAP_SNAPLEN: u32 = 262_144;
+
+const PCAP_MAGIC: u32 = 0xa1b2_c3d4;
+const PCAP_MAGIC_NANOS: u32 = 0xa1b2_3c4d;
+const PCAP_HEADER_LEN: usize = 24;
+const PCAP_RECORD_LEN: usize = 16;
+const PACKET_HOST: u16 = 0;
+const PACKET_OUTGOING: u16 = 4;
+// pkttype, hatype, halen, addr[8], protocol
+const COOKED_HEADER_LEN: usize = 16;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    /// From this process to the kernel (or the DPDK vrouter)
+    Outgoing,
+    /// From the kernel back to this process
+    Incoming,
+}
+
+/// Writes netlink datagrams as a pcap stream of LINKTYPE_NETLINK frames.
+///
+/// Each frame carries the 16 byte cooked header `nlmon` prepends: the
+/// packet type tells the direction, the protocol the netlink family.
+pub struct PcapWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> PcapWriter<W> {
+    /// Starts the stream with the pcap file header.
+    pub fn new(mut out: W) -> io::Result<PcapWriter<W>> {
+        let mut header = Vec::with_capacity(PCAP_HEADER_LEN);
+        header.extend(&PCAP_MAGIC.to_ne_bytes());
+        header.extend(&2u16.to_ne_bytes());
+        header.extend(&4u16.to_ne_bytes());
+        header.extend(&0i32.to_ne_bytes());
+        header.extend(&0u32.to_ne_bytes());
+        header.extend(&PCAP_SNAPLEN.to_ne_bytes());
+        header.extend(&LINKTYPE_NETLINK.to_ne_bytes());
+        out.write_all(&header[..])?;
+        Ok(PcapWriter { out })
+    }
+
+    pub fn write_frame(
+        &mut self,
+        timestamp: SystemTime,
+        direction: Direction,
+        protocol: u16,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let since_epoch = timestamp.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let orig_len = COOKED_HEADER_LEN + data.len();
+        let incl_len = orig_len.min(PCAP_SNAPLEN as usize);
+        let pkttype = match direction {
+            Direction::Outgoing => PACKET_OUTGOING,
+            Direction::Incoming => PACKET_HOST,
+        };
+        let mut frame = Vec::with_capacity(PCAP_RECORD_LEN + incl_len);
+        frame.extend(&(since_epoch.as_secs() as u32).to_ne_bytes());
+        frame.extend(&since_epoch.subsec_micros().to_ne_bytes());
+        frame.extend(&(incl_len as u32).to_ne_bytes());
+        frame.extend(&(orig_len as u32).to_ne_bytes());
+        frame.extend(&pkttype.to_be_bytes());
+        frame.extend(&ARPHRD_NETLINK.to_be_bytes());
+        frame.extend(&[0u8; 10]);
+        frame.extend(&protocol.to_be_bytes());
+        frame.extend(&data[..incl_len - COOKED_HEADER_LEN]);
+        self.out.write_all(&frame[..])
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> { self.out.flush() }
+
+    pub fn into_inner(self) -> W { self.out }
+}
+
+/// A frame read back from a LINKTYPE_NETLINK capture.
+#[derive(Debug, Clone)]
+pub struct PcapFrame<'a> {
+    pub timestamp: SystemTime,
+    pub direction: Direction,
+    pub protocol: u16,
+    /// The netlink datagram, possibly cut at the snapshot length
+    pub data: &'a [u8],
+}
+
+/// Walks the frames of a pcap file recorded on an `nlmon` interface, or
+/// written by the capture tap.
+pub fn read_pcap(buf: &[u8]) -> Result<PcapFrames<'_>, InvalidCapture> {
+    if buf.len() < PCAP_HEADER_LEN {
+        return Err(InvalidCapture::Truncated(0));
+    }
+    let magic = u32::from_ne_bytes([buf[0], buf[1], buf[2], buf[3]]);
+    let (swapped, nanos) = match magic {
+        PCAP_MAGIC => (false, false),
+        PCAP_MAGIC_NANOS => (false, true),
+        m if m.swap_bytes() == PCAP_MAGIC => (true, false),
+        m if m.swap_bytes() == PCAP_MAGIC_NANOS => (true, true),
+        m => return Err(InvalidCapture::Magic(m)),
+    };
+    let frames = PcapFrames {
+        buf,
+        offset: PCAP_HEADER_LEN,
+        swapped,
+        nanos,
+    };
+    match frames.read_u32(20) {
+        LINKTYPE_NETLINK => Ok(frames),
+        linktype => Err(InvalidCapture::LinkType(linktype)),
+    }
+}
+
+pub struct PcapFrames<'a> {
+    buf: &'a [u8],
+    offset: usize,
+    swapped: bool,
+    nanos: bool,
+}
+
+impl<'a> Iterator for PcapFrames<'a> {
+    type Item = Result<PcapFrame<'a>, InvalidCapture>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.buf.len() {
+            return None;
+        }
+        let offset = self.offset;
+        let frame_end = if self.buf.len() < offset + PCAP_RECORD_LEN {
+            None
+        } else {
+            Some(offset + PCAP_RECORD_LEN + self.read_u32(offset + 8) as usize)
+        };
+        let frame_end = match frame_end {
+            Some(end) if end <= self.buf.len() => end,
+            _ => {
+                self.offset = self.buf.len();
+                return Some(Err(InvalidCapture::Truncated(offset)));
+            }
+        };
+        self.offset = frame_end;
+        let frame = &self.buf[offset + PCAP_RECORD_LEN..frame_end];
+        if frame.len() < COOKED_HEADER_LEN {
+            return Some(Err(InvalidCapture::Truncated(offset)));
+        }
+        let secs = self.read_u32(offset) as u64;
+        let fraction = self.read_u32(offset + 4);
+        let since_epoch = if self.nanos {
+            Duration::new(secs, 0) + Duration::from_nanos(fraction as u64)
+        } else {
+            Duration::new(secs, 0) + Duration::from_micros(fraction as u64)
+        };
+        let direction = match u16::from_be_bytes([frame[0], frame[1]]) {
+            PACKET_OUTGOING => Direction::Outgoing,
+            _ => Direction::Incoming,
+        };
+        Some(Ok(PcapFrame {
+            timestamp: UNIX_EPOCH + since_epoch,
+            direction,
+            protocol: u16::from_be_bytes([frame[14], frame[15]]),
+            data: &frame[COOKED_HEADER_LEN..],
+        }))
+    }
+}
+
+impl<'a> PcapFrames<'a> {
+    fn read_u32(&self, offset: usize) -> u32 {
+        let b = &self.buf[offset..offset + 4];
+        let v = u32::from_ne_bytes([b[0], b[1], b[2], b[3]]);
+        if self.swapped {
+            v.swap_bytes()
+        } else {
+            v
+        }
+    }
+}
+
+/// Tap every netlink request and reply of this process into a pcap file.
+pub fn start_capture<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    start_capture_to(BufWriter::new(File::create(path)?))
+}
+
+pub fn start_capture_to<W: Write + Send + 'static>(out: W) -> io::Result<()> {
+    let out: Box<dyn Write + Send> = Box::new(out);
+    let writer = PcapWriter::new(out)?;
+    let mut tap = TAP.lock().unwrap_or_else(|e| e.into_inner());
+    *tap = Some(writer);
+    CAPTURING.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Stops the tap and flushes what has been captured so far.
+pub fn stop_capture() -> io::Result<()> {
+    CAPTURING.store(false, Ordering::Release);
+    let mut tap = TAP.lock().unwrap_or_else(|e| e.into_inner());
+    match tap.take() {
+        Some(mut writer) => writer.flush(),
+        None => Ok(()),
+    }
+}
+
+// Records a datagram on the tap when a capture is running. A failing tap is
+// stopped rather than failing the request.
+pub(crate) fn capture(direction: Direction, data: &[u8]) {
+    if !CAPTURING.load(Ordering::Acquire) {
+        return;
+    }
+    let mut tap = TAP.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(writer) = tap.as_mut() {
+        let now = SystemTime::now();
+        if writer.write_frame(now, direction, NETLINK_GENERIC as u16, data).is_err() {
+            CAPTURING.store(false, Ordering::Release);
+            *tap = None;
+        }
+    }
+}
+
+// private functions
+
+type Tap = Option<PcapWriter<Box<dyn Write + Send>>>;
+
+static CAPTURING: AtomicBool = AtomicBool::new(false);
+static TAP: Lazy<Mutex<Tap>> = Lazy::new(|| Mutex::new(None));
@@ -93,7 +93,7 @@ pub const NLMSG_OVERRUN: _nlmsg_type = 4;
 pub const NLMSG_MIN_TYPE: _nlmsg_type = 16;
 
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, AsBytes, FromBytes)]
 pub struct nlmsgerr {
     pub error: c_int,
     pub msg: nlmsghdr,
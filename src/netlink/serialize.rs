@@ -1,4 +1,3 @@
-use crate::vr_messages::*;
 use std::ffi::CString;
 use std::mem::size_of;
 
@@ -49,12 +48,3 @@ impl Serialize for Vec<u8> {
 
     fn serialize(&self, buf: &mut [u8]) { buf.copy_from_slice(&self[..]) }
 }
-
-impl<'a> Serialize for &'a Message {
-    fn len(&self) -> u32 { self.to_bytes().unwrap().len() as u32 }
-
-    fn serialize(&self, buf: &mut [u8]) {
-        let bytes = self.to_bytes().unwrap();
-        buf.copy_from_slice(&bytes[..])
-    }
-}
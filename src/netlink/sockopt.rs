@@ -0,0 +1,62 @@
+use super::error::NetlinkError;
+use super::raw::NETLINK_EXT_ACK;
+use libc::{c_int, c_void, socklen_t, suseconds_t, time_t, timeval};
+use libc::{SOL_NETLINK, SOL_SOCKET, SO_RCVBUF, SO_RCVTIMEO};
+use netlink_sys::Socket;
+use std::io;
+use std::mem::size_of;
+use std::os::unix::io::AsRawFd;
+use std::time::Duration;
+
+/// Ask the kernel to attach NLMSGERR_ATTR_* TLVs to error replies.
+pub fn set_ext_ack(socket: &Socket, enable: bool) -> Result<(), NetlinkError> {
+    setsockopt(socket, SOL_NETLINK, NETLINK_EXT_ACK as c_int, enable as c_int)
+}
+
+/// Bound how long a receive may block, `None` blocks until data arrives.
+///
+/// An expired receive fails with `io::ErrorKind::WouldBlock`.
+pub fn set_recv_timeout(
+    socket: &Socket,
+    timeout: Option<Duration>,
+) -> Result<(), NetlinkError> {
+    let mut tv = timeval { tv_sec: 0, tv_usec: 0 };
+    if let Some(timeout) = timeout {
+        if timeout == Duration::from_secs(0) {
+            let err = io::Error::new(io::ErrorKind::InvalidInput, "zero receive timeout");
+            return Err(NetlinkError::Io(err));
+        }
+        tv.tv_sec = timeout.as_secs() as time_t;
+        tv.tv_usec = timeout.subsec_micros() as suseconds_t;
+        // A zero timeval would block forever, round sub-microsecond values up
+        if tv.tv_sec == 0 && tv.tv_usec == 0 {
+            tv.tv_usec = 1;
+        }
+    }
+    setsockopt(socket, SOL_SOCKET, SO_RCVTIMEO, tv)
+}
+
+/// Ask for a receive buffer of `size` bytes, the kernel caps it at
+/// net.core.rmem_max.
+pub fn set_recv_buffer_size(socket: &Socket, size: usize) -> Result<(), NetlinkError> {
+    setsockopt(socket, SOL_SOCKET, SO_RCVBUF, size as c_int)
+}
+
+// private functions
+
+fn setsockopt<T>(
+    socket: &Socket,
+    level: c_int,
+    name: c_int,
+    value: T,
+) -> Result<(), NetlinkError> {
+    let value_ptr = &value as *const T as *const c_void;
+    let value_len = size_of::<T>() as socklen_t;
+    let res = unsafe {
+        libc::setsockopt(socket.as_raw_fd(), level, name, value_ptr, value_len)
+    };
+    if res < 0 {
+        return Err(NetlinkError::Io(io::Error::last_os_error()));
+    }
+    Ok(())
+}
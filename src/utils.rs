@@ -2,19 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use eui48::MacAddress;
-use std::ffi::{CStr, CString};
 use std::net::{Ipv4Addr, Ipv6Addr};
-use std::os::raw::{c_char, c_void};
+use std::os::raw::c_void;
 use rtnetlink::new_connection;
 use netlink_packet_route::IFF_UP;
 use netlink_packet_route::nlas::link::{InfoKind, Nla, Info};
 use ipnetwork::IpNetwork;
 use tokio::spawn;
 use futures::stream::TryStreamExt;
+use crate::netlink::{in_netns, NetNs};
+use crate::vr_messages::buffers::read_list;
 
 #[tokio::main]
 pub async fn create_vhost() -> Result<(), String> {
-    let (conn, handle, _) = new_connection().unwrap();
+    let (conn, handle, _) = new_connection().map_err(|e| format!("{}", e))?;
     spawn(conn);
     let mut request = handle.link().add();
     request.message_mut().nlas.push(nla_macaddr());
@@ -30,18 +31,15 @@ pub async fn create_vhost() -> Result<(), String> {
 
 #[tokio::main]
 pub async fn add_vhost_ip(ipstr: String) -> Result<(), String> {
-    let ip: IpNetwork = ipstr.parse().unwrap_or_else(|_| {
-        eprintln!("invalid address");
-        std::process::exit(1);
-    });
-    let (conn, handle, _) = new_connection().unwrap();
+    let ip: IpNetwork = ipstr.parse().map_err(|e| format!("{}", e))?;
+    let (conn, handle, _) = new_connection().map_err(|e| format!("{}", e))?;
     spawn(conn);
     let mut links = handle
         .link()
         .get()
         .set_name_filter("vhost0".to_string())
         .execute();
-    if let Some(link) = links.try_next().await.unwrap() {
+    if let Some(link) = links.try_next().await.map_err(|e| format!("{}", e))? {
         return handle
             .address()
             .add(link.header.index, ip.ip(), ip.prefix())
@@ -53,6 +51,16 @@ pub async fn add_vhost_ip(ipstr: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Same as `create_vhost`, with the link created in `netns`.
+pub fn create_vhost_in(netns: &NetNs) -> Result<(), String> {
+    in_netns(netns, create_vhost).map_err(|e| format!("{}", e))?
+}
+
+/// Same as `add_vhost_ip`, for the vhost0 of `netns`.
+pub fn add_vhost_ip_in(netns: &NetNs, ipstr: String) -> Result<(), String> {
+    in_netns(netns, move || add_vhost_ip(ipstr)).map_err(|e| format!("{}", e))?
+}
+
 fn nla_macaddr() -> Nla {
     Nla::Address(vec![0x00, 0x05, 0x85, 0x00, 0x00, 0x01])
 }
@@ -69,50 +77,12 @@ fn nla_linkinfo() -> Nla {
     ])
 }
 
-pub fn str_to_cchar(s: &str) -> *const c_char {
-    let c_str = CString::new(s).unwrap();
-    let c_string: *const c_char = c_str.as_ptr() as *const c_char;
-    c_string
-}
-
-pub fn cchar_to_str(s: *const c_char) -> &'static str {
-    unsafe {
-        let char_ptr = CStr::from_ptr(s);
-        char_ptr.to_str().unwrap()
-    }
-}
-
 pub fn into_raw_ptr<T>(term: &T) -> *const c_void {
     &(*term) as *const _ as *const c_void
 }
 
-pub fn alloc_buf(len: usize) -> *mut u8 {
-    let vec = vec![0; len];
-    Box::into_raw(vec.into_boxed_slice()) as *mut u8
-}
-
-pub fn free_buf<T: Clone>(buf: *mut T, buf_len: usize) -> Vec<T> {
-    unsafe {
-        let s = std::slice::from_raw_parts_mut(buf, buf_len);
-        let r = Box::from_raw(s);
-        r.to_vec()
-    }
-}
-
-pub fn into_mut_ptr<T: Clone>(vec: &Vec<T>) -> *mut T {
-    let b: Box<[T]> = vec.clone().into_boxed_slice();
-    Box::into_raw(b) as *mut _
-}
-
-pub fn write_mac(mac_addr: MacAddress) -> *mut i8 {
-    let v: Vec<i8> = Vec::new();
-    let octets = mac_addr.as_bytes().to_vec();
-    let mac = octets.iter().fold(v, |mut acc, &o| {
-        acc.push(o as i8);
-        acc
-    });
-
-    into_mut_ptr(&mac)
+pub fn write_mac(mac_addr: MacAddress) -> Vec<i8> {
+    mac_addr.as_bytes().iter().map(|&o| o as i8).collect()
 }
 
 pub fn write_ip4(ip: Ipv4Addr) -> u32 {
@@ -142,11 +112,8 @@ pub fn write_ip6(ip: Ipv6Addr) -> u128 {
 
 pub fn read_mac_addr(mac_addr: *mut i8, mac_addr_size: u32) -> MacAddress {
     if mac_addr_size == libc::ETH_ALEN as u32 {
-        MacAddress::from_bytes(&*free_buf::<u8>(
-            mac_addr as *mut u8,
-            libc::ETH_ALEN as usize,
-        ))
-        .unwrap()
+        let octets = read_list(mac_addr as *const u8, mac_addr_size);
+        MacAddress::from_bytes(&octets).unwrap()
     } else {
         MacAddress::nil()
     }
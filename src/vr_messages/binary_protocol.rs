@@ -0,0 +1,499 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::buffers;
+use super::error::CodecError;
+use super::vr_types_binding::*;
+use std::ffi::{CStr, CString};
+use std::mem::size_of;
+use std::os::raw::c_char;
+
+/// A sandesh laid out the way the generated C codec writes it: the sandesh
+/// name, then every field with its thrift type and id, then a T_STOP byte.
+/// Fields are always written, unset or not, and `NULL` strings and lists go
+/// out empty.
+pub trait SandeshStruct {
+    const NAME: &'static str;
+
+    fn write_fields(&self, w: &mut BinaryWriter);
+
+    /// Decodes field `id` of type `ty`, skipping it when the id is unknown.
+    fn read_field(
+        &mut self,
+        id: i16,
+        ty: ThriftType,
+        r: &mut BinaryReader,
+    ) -> Result<(), CodecError>;
+
+    /// Frees the strings and lists the codec allocated while decoding.
+    fn free_buffers(&mut self) {}
+}
+
+/// A thrift scalar, written big-endian.
+pub trait Scalar: Copy {
+    const TYPE: ThriftType;
+
+    fn put(self, w: &mut BinaryWriter);
+
+    fn get(r: &mut BinaryReader) -> Result<Self, CodecError>;
+}
+
+macro_rules! scalar {
+    ($ty:ty, $thrift_type:ident) => {
+        impl Scalar for $ty {
+            const TYPE: ThriftType = $thrift_type;
+
+            fn put(self, w: &mut BinaryWriter) {
+                w.buf.extend_from_slice(&self.to_be_bytes())
+            }
+
+            fn get(r: &mut BinaryReader) -> Result<$ty, CodecError> {
+                let mut bytes = [0; size_of::<$ty>()];
+                bytes.copy_from_slice(r.take(size_of::<$ty>())?);
+                Ok(<$ty>::from_be_bytes(bytes))
+            }
+        }
+    };
+}
+
+scalar!(i8, ThriftType_T_BYTE);
+scalar!(i16, ThriftType_T_I16);
+scalar!(i32, ThriftType_T_I32);
+scalar!(i64, ThriftType_T_I64);
+scalar!(u16, ThriftType_T_U16);
+scalar!(u32, ThriftType_T_U32);
+scalar!(u64, ThriftType_T_U64);
+
+impl Scalar for bool {
+    const TYPE: ThriftType = ThriftType_T_BOOL;
+
+    fn put(self, w: &mut BinaryWriter) { w.buf.push(self as u8) }
+
+    fn get(r: &mut BinaryReader) -> Result<bool, CodecError> { Ok(r.read_u8()? != 0) }
+}
+
+/// The name a sandesh starts with.
+pub fn sandesh_name(buf: &[u8]) -> Result<&str, CodecError> {
+    let name = BinaryReader::new(buf)
+        .read_string()
+        .map_err(|_| CodecError::InvalidSandeshName)?;
+    std::str::from_utf8(name).map_err(|_| CodecError::InvalidSandeshName)
+}
+
+/// The bytes the sandesh at the head of `buf` spans, from its name to its
+/// T_STOP byte, whichever object it is.
+pub fn sandesh_len(buf: &[u8]) -> Result<usize, CodecError> {
+    let mut r = BinaryReader::new(buf);
+    r.read_string().map_err(|_| CodecError::InvalidSandeshName)?;
+    r.skip(ThriftType_T_STRUCT)?;
+    Ok(r.pos)
+}
+
+/// Encodes `sandesh` as `*_write_binary_to_buffer` does.
+pub fn write_sandesh<S: SandeshStruct + ?Sized>(sandesh: &S) -> Vec<u8> {
+    let mut w = BinaryWriter::default();
+    w.write_string(S::NAME.as_bytes());
+    sandesh.write_fields(&mut w);
+    w.write_field_stop();
+    w.buf
+}
+
+/// Decodes the sandesh at the head of `buf` into `sandesh` as
+/// `*_read_binary_from_buffer` does, and returns the bytes it spans.
+pub fn read_sandesh<S: SandeshStruct + ?Sized>(
+    sandesh: &mut S,
+    buf: &[u8],
+) -> Result<usize, CodecError> {
+    read_fields(sandesh, buf).map(|r| r.pos)
+}
+
+/// Same as `read_sandesh`, but returns the length
+/// `*_read_binary_from_buffer` reports, which leaves the elements of
+/// `list<byte>` fields out.
+pub fn read_sandesh_rxfer<S: SandeshStruct + ?Sized>(
+    sandesh: &mut S,
+    buf: &[u8],
+) -> Result<usize, CodecError> {
+    read_fields(sandesh, buf).map(|r| r.pos - r.uncounted)
+}
+
+#[derive(Debug, Default)]
+pub struct BinaryWriter {
+    buf: Vec<u8>,
+}
+
+impl BinaryWriter {
+    pub fn write_field<T: Scalar>(&mut self, id: i16, value: T) {
+        self.write_field_begin(T::TYPE, id);
+        value.put(self);
+    }
+
+    // The enums of the IDL go out as i32
+    pub fn write_enum_field(&mut self, id: i16, value: u32) {
+        self.write_field(id, value as i32)
+    }
+
+    pub fn write_str_field(&mut self, id: i16, value: &str) {
+        self.write_field_begin(ThriftType_T_STRING, id);
+        self.write_string(value.as_bytes());
+    }
+
+    pub fn write_slice_field<T: Scalar>(&mut self, id: i16, elems: &[T]) {
+        self.write_field_begin(ThriftType_T_LIST, id);
+        self.buf.push(T::TYPE as u8);
+        (elems.len() as i32).put(self);
+        elems.iter().for_each(|elem| elem.put(self));
+    }
+
+    /// # Safety
+    ///
+    /// `value` is either `NULL` or a nul-terminated string.
+    pub unsafe fn write_string_field(&mut self, id: i16, value: *const c_char) {
+        self.write_field_begin(ThriftType_T_STRING, id);
+        if value.is_null() {
+            self.write_string(&[]);
+        } else {
+            self.write_string(CStr::from_ptr(value).to_bytes());
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `list` is either `NULL` or points to `size` elements.
+    pub unsafe fn write_list_field<T: Scalar>(
+        &mut self,
+        id: i16,
+        list: *const T,
+        size: u32,
+    ) {
+        if list.is_null() {
+            self.write_slice_field::<T>(id, &[]);
+        } else {
+            self.write_slice_field(id, std::slice::from_raw_parts(list, size as usize));
+        }
+    }
+
+    // private functions
+
+    fn write_field_begin(&mut self, ty: ThriftType, id: i16) {
+        self.buf.push(ty as u8);
+        id.put(self);
+    }
+
+    fn write_field_stop(&mut self) { self.buf.push(ThriftType_T_STOP as u8) }
+
+    fn write_string(&mut self, s: &[u8]) {
+        (s.len() as i32).put(self);
+        self.buf.extend_from_slice(s);
+    }
+}
+
+/// The structs, lists and maps `BinaryReader::skip` steps into, one in
+/// another, before giving up on the buffer.
+pub const MAX_SKIP_DEPTH: usize = 64;
+
+#[derive(Debug)]
+pub struct BinaryReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    // The bytes the C reader leaves out of its count
+    uncounted: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    pub fn new(buf: &'a [u8]) -> BinaryReader<'a> {
+        BinaryReader {
+            buf,
+            pos: 0,
+            uncounted: 0,
+        }
+    }
+
+    pub fn position(&self) -> usize { self.pos }
+
+    pub fn read_field<T: Scalar>(
+        &mut self,
+        ty: ThriftType,
+        value: &mut T,
+    ) -> Result<(), CodecError> {
+        if ty != T::TYPE {
+            return self.skip(ty);
+        }
+        *value = T::get(self)?;
+        Ok(())
+    }
+
+    pub fn read_enum_field(
+        &mut self,
+        ty: ThriftType,
+        value: &mut u32,
+    ) -> Result<(), CodecError> {
+        let mut raw = *value as i32;
+        self.read_field(ty, &mut raw)?;
+        *value = raw as u32;
+        Ok(())
+    }
+
+    pub fn read_str_field(
+        &mut self,
+        ty: ThriftType,
+        value: &mut String,
+    ) -> Result<(), CodecError> {
+        if ty != ThriftType_T_STRING {
+            return self.skip(ty);
+        }
+        *value = String::from_utf8_lossy(self.read_string()?).into_owned();
+        Ok(())
+    }
+
+    /// Leaves `elems` untouched when the list holds elements of another type.
+    pub fn read_vec_field<T: Scalar>(
+        &mut self,
+        ty: ThriftType,
+        elems: &mut Vec<T>,
+    ) -> Result<(), CodecError> {
+        if ty != ThriftType_T_LIST {
+            return self.skip(ty);
+        }
+        let elem_ty = self.read_u8()? as ThriftType;
+        let len = self.read_size()?;
+        if elem_ty != T::TYPE {
+            return (0..len).try_for_each(|_| self.skip(elem_ty));
+        }
+        self.expect(len * size_of::<T>())?;
+        *elems = (0..len).map(|_| T::get(self)).collect::<Result<_, _>>()?;
+        if T::TYPE == ThriftType_T_BYTE {
+            self.uncounted += len;
+        }
+        Ok(())
+    }
+
+    /// Strings are handed out as `buffers::set_string` allocates them, cut
+    /// at the first nul byte.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be `NULL` or a string decoded before, which is freed.
+    pub unsafe fn read_string_field(
+        &mut self,
+        ty: ThriftType,
+        value: &mut *mut c_char,
+    ) -> Result<(), CodecError> {
+        if ty != ThriftType_T_STRING {
+            return self.skip(ty);
+        }
+        let s = self.read_string()?;
+        let s = s.split(|&b| b == 0).next().unwrap_or_default();
+        let s = CString::new(s).map_err(|_| CodecError::Read(libc::EINVAL))?;
+        buffers::set_string(value, &s);
+        Ok(())
+    }
+
+    /// Lists are handed out as `buffers::set_list` allocates them.
+    ///
+    /// # Safety
+    ///
+    /// `list` must be `NULL` or a list of `size` decoded before, which is
+    /// freed.
+    pub unsafe fn read_list_field<T: Scalar>(
+        &mut self,
+        ty: ThriftType,
+        list: &mut *mut T,
+        size: &mut u32,
+    ) -> Result<(), CodecError> {
+        let mut elems = Vec::new();
+        self.read_vec_field(ty, &mut elems)?;
+        buffers::set_list(list, size, &elems);
+        Ok(())
+    }
+
+    /// Steps over a value of type `ty`, up to `MAX_SKIP_DEPTH` levels of
+    /// structs, lists and maps deep.
+    pub fn skip(&mut self, ty: ThriftType) -> Result<(), CodecError> {
+        self.skip_nested(ty, 0)
+    }
+
+    // private functions
+
+    #[allow(non_upper_case_globals)]
+    fn skip_nested(&mut self, ty: ThriftType, depth: usize) -> Result<(), CodecError> {
+        let depth = depth + 1;
+        match ty {
+            ThriftType_T_BOOL | ThriftType_T_BYTE => self.take(1).map(|_| ()),
+            ThriftType_T_I16 | ThriftType_T_U16 => self.take(2).map(|_| ()),
+            ThriftType_T_I32 | ThriftType_T_U32 | ThriftType_T_IPV4 => {
+                self.take(4).map(|_| ())
+            }
+            ThriftType_T_I64 | ThriftType_T_U64 | ThriftType_T_DOUBLE => {
+                self.take(8).map(|_| ())
+            }
+            ThriftType_T_UUID => self.take(16).map(|_| ()),
+            ThriftType_T_STRING | ThriftType_T_XML => self.read_string().map(|_| ()),
+            ThriftType_T_IPADDR => match self.read_u8()? as i32 {
+                libc::AF_INET => self.take(4).map(|_| ()),
+                libc::AF_INET6 => self.take(16).map(|_| ()),
+                _ => Err(CodecError::Read(libc::EINVAL)),
+            },
+            ThriftType_T_STRUCT | ThriftType_T_LIST | ThriftType_T_SET | ThriftType_T_MAP
+                if depth > MAX_SKIP_DEPTH =>
+            {
+                Err(CodecError::Read(libc::EINVAL))
+            }
+            ThriftType_T_STRUCT => loop {
+                let ty = self.read_u8()? as ThriftType;
+                if ty == ThriftType_T_STOP {
+                    return Ok(());
+                }
+                self.take(2)?;
+                self.skip_nested(ty, depth)?;
+            },
+            ThriftType_T_LIST | ThriftType_T_SET => {
+                let elem_ty = self.read_u8()? as ThriftType;
+                let len = self.read_size()?;
+                (0..len).try_for_each(|_| self.skip_nested(elem_ty, depth))
+            }
+            ThriftType_T_MAP => {
+                let key_ty = self.read_u8()? as ThriftType;
+                let value_ty = self.read_u8()? as ThriftType;
+                let len = self.read_size()?;
+                (0..len).try_for_each(|_| {
+                    self.skip_nested(key_ty, depth)?;
+                    self.skip_nested(value_ty, depth)
+                })
+            }
+            _ => Err(CodecError::Read(libc::EINVAL)),
+        }
+    }
+
+    fn read_u8(&mut self) -> Result<u8, CodecError> { Ok(self.take(1)?[0]) }
+
+    fn read_size(&mut self) -> Result<usize, CodecError> {
+        match i32::get(self)? {
+            size if size < 0 => Err(CodecError::Read(libc::EINVAL)),
+            size => Ok(size as usize),
+        }
+    }
+
+    fn read_string(&mut self) -> Result<&'a [u8], CodecError> {
+        let len = self.read_size()?;
+        self.take(len)
+    }
+
+    fn expect(&self, len: usize) -> Result<(), CodecError> {
+        match self.pos.checked_add(len) {
+            Some(end) if end <= self.buf.len() => Ok(()),
+            _ => Err(CodecError::Read(libc::EINVAL)),
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CodecError> {
+        self.expect(len)?;
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+}
+
+/// Lays a bindgen struct out as a sandesh, with its fields written the way
+/// `priv/vr.sandesh` declares them. Lists name the field holding their size.
+macro_rules! sandesh {
+    ($sandesh:ident {
+        $($id:literal: $kind:ident $(<$elem:ident>)? $field:ident $([$size:ident])?;)*
+    }) => {
+        impl SandeshStruct for $sandesh {
+            const NAME: &'static str = stringify!($sandesh);
+
+            fn write_fields(&self, w: &mut BinaryWriter) {
+                $(sandesh_field!(
+                    write w, $id, $kind $(<$elem>)?, self.$field $(, self.$size)?
+                );)*
+            }
+
+            fn read_field(
+                &mut self,
+                id: i16,
+                ty: ThriftType,
+                r: &mut BinaryReader,
+            ) -> Result<(), CodecError> {
+                match id {
+                    $($id => sandesh_field!(
+                        read r, ty, $kind $(<$elem>)?, self.$field $(, self.$size)?
+                    ),)*
+                    _ => r.skip(ty),
+                }
+            }
+
+            fn free_buffers(&mut self) {
+                $(sandesh_field!(
+                    free $kind $(<$elem>)?, self.$field $(, self.$size)?
+                );)*
+            }
+        }
+    };
+}
+
+macro_rules! sandesh_field {
+    (write $w:ident, $id:expr, enum<$enum:ident>, $value:expr) => {
+        $w.write_enum_field($id, $value)
+    };
+    (write $w:ident, $id:expr, string, $value:expr) => {
+        unsafe { $w.write_string_field($id, $value) }
+    };
+    (write $w:ident, $id:expr, list<$elem:ident>, $list:expr, $size:expr) => {
+        unsafe { $w.write_list_field::<sandesh_type!($elem)>($id, $list, $size) }
+    };
+    (write $w:ident, $id:expr, $kind:ident, $value:expr) => {
+        $w.write_field::<sandesh_type!($kind)>($id, $value)
+    };
+    (read $r:ident, $ty:expr, enum<$enum:ident>, $value:expr) => {
+        $r.read_enum_field($ty, &mut $value)
+    };
+    (read $r:ident, $ty:expr, string, $value:expr) => {
+        unsafe { $r.read_string_field($ty, &mut $value) }
+    };
+    (read $r:ident, $ty:expr, list<$elem:ident>, $list:expr, $size:expr) => {
+        unsafe {
+            $r.read_list_field::<sandesh_type!($elem)>($ty, &mut $list, &mut $size)
+        }
+    };
+    (read $r:ident, $ty:expr, $kind:ident, $value:expr) => {
+        $r.read_field::<sandesh_type!($kind)>($ty, &mut $value)
+    };
+    (free string, $value:expr) => {
+        unsafe { $crate::vr_messages::buffers::free_string(&mut $value) }
+    };
+    (free list<$elem:ident>, $list:expr, $size:expr) => {
+        unsafe { $crate::vr_messages::buffers::free_list(&mut $list, &mut $size) }
+    };
+    (free enum<$enum:ident>, $value:expr) => {
+        ()
+    };
+    (free $kind:ident, $value:expr) => {
+        ()
+    };
+}
+
+macro_rules! sandesh_type {
+    (byte) => { i8 };
+    ($kind:ident) => { $kind };
+}
+
+// private functions
+
+fn read_fields<'a, S: SandeshStruct + ?Sized>(
+    sandesh: &mut S,
+    buf: &'a [u8],
+) -> Result<BinaryReader<'a>, CodecError> {
+    let mut r = BinaryReader::new(buf);
+    if r.read_string()? != S::NAME.as_bytes() {
+        return Err(CodecError::InvalidSandeshName);
+    }
+    loop {
+        let ty = r.read_u8()? as ThriftType;
+        if ty == ThriftType_T_STOP {
+            return Ok(r);
+        }
+        let id = i16::get(&mut r)?;
+        sandesh.read_field(id, ty, &mut r)?;
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// The bindgen sandesh structs only hold raw pointers to their strings and
+// lists. `Encoder` owns what is lent to the codec while a sandesh is written,
+// `Decoder` frees what the codec allocated while one is read, so neither
+// outlives the call.
+
+use super::binary_protocol::SandeshStruct;
+use super::error::CodecError;
+use super::vr_types::VrSandesh;
+use eui48::MacAddress;
+use std::any::Any;
+use std::ffi::{CStr, CString};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_char;
+use std::ptr;
+
+/// A sandesh to encode, owning the strings and lists it points to.
+pub struct Encoder<S> {
+    sandesh: S,
+    buffers: Vec<Box<dyn Any>>,
+}
+
+impl<S: VrSandesh<Type = S>> Encoder<S> {
+    pub fn new() -> Encoder<S> {
+        Encoder {
+            sandesh: S::new(),
+            buffers: Vec::new(),
+        }
+    }
+}
+
+impl<S: VrSandesh<Type = S>> Default for Encoder<S> {
+    fn default() -> Encoder<S> { Encoder::new() }
+}
+
+impl<S> Encoder<S> {
+    /// Copies `elems` to a buffer living as long as the encoder, `NULL` when
+    /// there are none.
+    pub fn list<T: Copy + 'static>(&mut self, elems: &[T]) -> *mut T {
+        if elems.is_empty() {
+            return ptr::null_mut();
+        }
+        let mut buffer = elems.to_vec().into_boxed_slice();
+        let list = buffer.as_mut_ptr();
+        self.buffers.push(Box::new(buffer));
+        list
+    }
+
+    /// Same as `list`, for a field held as a list of `T`, with its size.
+    pub fn list_field<T: Copy + 'static, L: ListField<T>>(
+        &mut self,
+        field: &L,
+    ) -> (*mut T, u32) {
+        let elems = field.to_list();
+        (self.list(&elems), elems.len() as u32)
+    }
+
+    /// Same as `list`, for a nul terminated string.
+    pub fn string(&mut self, s: &str) -> Result<*mut c_char, CodecError> {
+        let buffer = CString::new(s).map_err(|_| CodecError::InvalidString)?;
+        let string = buffer.as_ptr() as *mut c_char;
+        self.buffers.push(Box::new(buffer));
+        Ok(string)
+    }
+}
+
+impl<S> Deref for Encoder<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S { &self.sandesh }
+}
+
+impl<S> DerefMut for Encoder<S> {
+    fn deref_mut(&mut self) -> &mut S { &mut self.sandesh }
+}
+
+/// A sandesh to decode into, freeing the strings and lists the codec
+/// allocated for it when dropped.
+pub struct Decoder<S: SandeshStruct> {
+    sandesh: S,
+}
+
+impl<S: VrSandesh<Type = S>> Decoder<S> {
+    pub fn new() -> Decoder<S> { Decoder { sandesh: S::new() } }
+}
+
+impl<S: VrSandesh<Type = S>> Default for Decoder<S> {
+    fn default() -> Decoder<S> { Decoder::new() }
+}
+
+impl<S: SandeshStruct> Deref for Decoder<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S { &self.sandesh }
+}
+
+impl<S: SandeshStruct> DerefMut for Decoder<S> {
+    fn deref_mut(&mut self) -> &mut S { &mut self.sandesh }
+}
+
+impl<S: SandeshStruct> Drop for Decoder<S> {
+    fn drop(&mut self) { self.sandesh.free_buffers() }
+}
+
+/// Copies a decoded list of `size` elements out, an empty one when `NULL`.
+pub(crate) fn read_list<T: Copy>(list: *const T, size: u32) -> Vec<T> {
+    if list.is_null() || size == 0 {
+        return Vec::new();
+    }
+    unsafe { std::slice::from_raw_parts(list, size as usize).to_vec() }
+}
+
+/// Same as `read_list`, for a field held as a list of `T`.
+pub(crate) fn read_list_field<T: Copy, L: ListField<T>>(list: *const T, size: u32) -> L {
+    L::from_list(read_list(list, size))
+}
+
+/// A message field encoded as a `list<T>` of the IDL.
+pub trait ListField<T>: Sized {
+    fn to_list(&self) -> Vec<T>;
+
+    fn from_list(list: Vec<T>) -> Self;
+}
+
+impl<T: Copy> ListField<T> for Vec<T> {
+    fn to_list(&self) -> Vec<T> { self.clone() }
+
+    fn from_list(list: Vec<T>) -> Self { list }
+}
+
+impl ListField<i8> for Vec<u8> {
+    fn to_list(&self) -> Vec<i8> { byte_list(self) }
+
+    fn from_list(list: Vec<i8>) -> Self { list.into_iter().map(|b| b as u8).collect() }
+}
+
+/// Without a nul terminator, empty when not UTF-8.
+impl ListField<i8> for String {
+    fn to_list(&self) -> Vec<i8> { byte_list(self.as_bytes()) }
+
+    fn from_list(list: Vec<i8>) -> Self {
+        String::from_utf8(Vec::<u8>::from_list(list)).unwrap_or_default()
+    }
+}
+
+/// Empty when nil, nil unless 6 bytes long.
+impl ListField<i8> for MacAddress {
+    fn to_list(&self) -> Vec<i8> {
+        match self.is_nil() {
+            true => Vec::new(),
+            false => byte_list(self.as_bytes()),
+        }
+    }
+
+    fn from_list(list: Vec<i8>) -> Self {
+        let bytes = Vec::<u8>::from_list(list);
+        MacAddress::from_bytes(&bytes).unwrap_or_else(|_| MacAddress::nil())
+    }
+}
+
+/// Empty when unspecified, unspecified unless 16 bytes long.
+impl ListField<i8> for Ipv6Addr {
+    fn to_list(&self) -> Vec<i8> {
+        match self.is_unspecified() {
+            true => Vec::new(),
+            false => byte_list(&self.octets()),
+        }
+    }
+
+    fn from_list(list: Vec<i8>) -> Self {
+        let mut octets = [0u8; 16];
+        if list.len() != octets.len() {
+            return Ipv6Addr::UNSPECIFIED;
+        }
+        octets.copy_from_slice(&Vec::<u8>::from_list(list));
+        Ipv6Addr::from(octets)
+    }
+}
+
+impl ListField<u64> for Vec<Ipv4Addr> {
+    fn to_list(&self) -> Vec<u64> {
+        self.iter().map(|&ip| u32::from(ip) as u64).collect()
+    }
+
+    fn from_list(list: Vec<u64>) -> Self {
+        list.into_iter().map(|ip| Ipv4Addr::from(ip as u32)).collect()
+    }
+}
+
+/// Copies a decoded string out, an empty one when `NULL`.
+pub(crate) fn read_string(s: *const c_char) -> String {
+    if s.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(s).to_string_lossy().into_owned() }
+}
+
+/// Frees a list allocated by the codec while decoding, and resets it.
+///
+/// # Safety
+///
+/// `list` must be `NULL` or come from `set_list`, with `size` elements.
+pub unsafe fn free_list<T>(list: &mut *mut T, size: &mut u32) {
+    if !list.is_null() {
+        free(*list, *size as usize);
+    }
+    *list = ptr::null_mut();
+    *size = 0;
+}
+
+/// Frees a string allocated by the codec while decoding, and resets it.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or come from `set_string`.
+pub unsafe fn free_string(s: &mut *mut c_char) {
+    if !s.is_null() {
+        let len = CStr::from_ptr(*s).to_bytes_with_nul().len();
+        free(*s, len);
+    }
+    *s = ptr::null_mut();
+}
+
+/// Replaces a decoded list, allocated the way the codec in use does.
+///
+/// # Safety
+///
+/// Same as `free_list`.
+pub unsafe fn set_list<T: Copy>(list: &mut *mut T, size: &mut u32, elems: &[T]) {
+    free_list(list, size);
+    *list = alloc(elems);
+    *size = elems.len() as u32;
+}
+
+/// Replaces a decoded string, allocated the way the codec in use does.
+///
+/// # Safety
+///
+/// Same as `free_string`.
+pub unsafe fn set_string(s: &mut *mut c_char, value: &CStr) {
+    free_string(s);
+    *s = alloc(value.to_bytes_with_nul()) as *mut c_char;
+}
+
+// private functions
+
+fn byte_list(bytes: &[u8]) -> Vec<i8> { bytes.iter().map(|&b| b as i8).collect() }
+
+// The Rust codec allocates with the global allocator, the C one with malloc.
+#[cfg(feature = "native-codec")]
+fn alloc<T: Copy>(elems: &[T]) -> *mut T {
+    Box::into_raw(elems.to_vec().into_boxed_slice()) as *mut T
+}
+
+#[cfg(feature = "native-codec")]
+unsafe fn free<T>(elems: *mut T, len: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(elems, len)))
+}
+
+#[cfg(not(feature = "native-codec"))]
+fn alloc<T: Copy>(elems: &[T]) -> *mut T {
+    unsafe {
+        let buf = libc::malloc(std::mem::size_of_val(elems).max(1)) as *mut T;
+        if !buf.is_null() {
+            ptr::copy_nonoverlapping(elems.as_ptr(), buf, elems.len());
+        }
+        buf
+    }
+}
+
+#[cfg(not(feature = "native-codec"))]
+unsafe fn free<T>(elems: *mut T, _len: usize) { libc::free(elems as *mut libc::c_void) }
@@ -0,0 +1,284 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// Takes a sandesh buffer apart field by field, e.g. to see which field of a
+// request the vrouter turned down. The binary protocol carries the type of
+// every field, so any object can be walked; priv/vr.sandesh only lends the
+// field names.
+
+use super::binary_protocol::{BinaryReader, Scalar, MAX_SKIP_DEPTH};
+use super::error::CodecError;
+use super::idl::SandeshType;
+use super::vr_types_binding::*;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// The values shown of a list before eliding the rest.
+const MAX_LIST_VALUES: usize = 16;
+
+/// A sandesh buffer, as `dissect` takes it apart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dissection<'a> {
+    /// From `sandesh_info_t::sname_from_bytes`.
+    pub name: String,
+    pub fields: Vec<DissectedField>,
+    /// The bytes of the sandesh, from its name to its T_STOP byte.
+    pub bytes: &'a [u8],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DissectedField {
+    pub id: i16,
+    /// As declared in priv/vr.sandesh, `None` for unknown objects or ids.
+    pub name: Option<&'static str>,
+    pub ty: ThriftType,
+    /// Where the field starts, from the start of the sandesh.
+    pub offset: usize,
+    /// The bytes of the field, its type and id included.
+    pub length: usize,
+    pub value: FieldValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    Byte(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    Double(f64),
+    String(String),
+    Ipv4(Ipv4Addr),
+    IpAddr(IpAddr),
+    Uuid([u8; 16]),
+    /// A list or a set.
+    List {
+        elem_ty: ThriftType,
+        elems: Vec<FieldValue>,
+    },
+    /// A struct or a map, stepped over.
+    Raw(Vec<u8>),
+}
+
+/// Takes apart the sandesh at the head of `buf`, e.g. the bytes of
+/// `Message::to_bytes`.
+pub fn dissect(buf: &[u8]) -> Result<Dissection<'_>, CodecError> {
+    let name = sandesh_info_t::sname_from_bytes(buf)?.to_string();
+    let sandesh_type = SandeshType::from_name(&name);
+    let mut r = BinaryReader::new(buf);
+    r.skip(ThriftType_T_STRING)
+        .map_err(|_| CodecError::InvalidSandeshName)?;
+    let mut fields = Vec::new();
+    loop {
+        let offset = r.position();
+        let ty = i8::get(&mut r)? as u8 as ThriftType;
+        if ty == ThriftType_T_STOP {
+            break;
+        }
+        let id = i16::get(&mut r)?;
+        let value = read_value(&mut r, buf, ty, 1)?;
+        fields.push(DissectedField {
+            id,
+            name: sandesh_type.and_then(|t| t.field_name(id)),
+            ty,
+            offset,
+            length: r.position() - offset,
+            value,
+        });
+    }
+    Ok(Dissection {
+        name,
+        fields,
+        bytes: &buf[..r.position()],
+    })
+}
+
+/// The name of a thrift type, as the IDL spells it.
+#[allow(non_upper_case_globals)]
+pub fn type_name(ty: ThriftType) -> &'static str {
+    match ty {
+        ThriftType_T_STOP => "stop",
+        ThriftType_T_VOID => "void",
+        ThriftType_T_BOOL => "bool",
+        ThriftType_T_BYTE => "byte",
+        ThriftType_T_I16 => "i16",
+        ThriftType_T_I32 => "i32",
+        ThriftType_T_I64 => "i64",
+        ThriftType_T_U16 => "u16",
+        ThriftType_T_U32 => "u32",
+        ThriftType_T_U64 => "u64",
+        ThriftType_T_DOUBLE => "double",
+        ThriftType_T_STRING => "string",
+        ThriftType_T_STRUCT => "struct",
+        ThriftType_T_MAP => "map",
+        ThriftType_T_SET => "set",
+        ThriftType_T_LIST => "list",
+        ThriftType_T_UTF8 => "utf8",
+        ThriftType_T_UTF16 => "utf16",
+        ThriftType_T_XML => "xml",
+        ThriftType_T_IPV4 => "ipv4",
+        ThriftType_T_UUID => "uuid",
+        ThriftType_T_IPADDR => "ipaddr",
+        _ => "unknown",
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldValue::Bool(v) => write!(f, "{}", v),
+            FieldValue::Byte(v) => write!(f, "{}", v),
+            FieldValue::I16(v) => write!(f, "{}", v),
+            FieldValue::I32(v) => write!(f, "{}", v),
+            FieldValue::I64(v) => write!(f, "{}", v),
+            FieldValue::U16(v) => write!(f, "{}", v),
+            FieldValue::U32(v) => write!(f, "{}", v),
+            FieldValue::U64(v) => write!(f, "{}", v),
+            FieldValue::Double(v) => write!(f, "{}", v),
+            FieldValue::String(v) => write!(f, "{:?}", v),
+            FieldValue::Ipv4(v) => write!(f, "{}", v),
+            FieldValue::IpAddr(v) => write!(f, "{}", v),
+            FieldValue::Uuid(v) => v.iter().try_for_each(|b| write!(f, "{:02x}", b)),
+            FieldValue::List { elems, .. } => {
+                write!(f, "[")?;
+                for (i, elem) in elems.iter().take(MAX_LIST_VALUES).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", elem)?;
+                }
+                if elems.len() > MAX_LIST_VALUES {
+                    write!(f, ", ...")?;
+                }
+                write!(f, "]")
+            }
+            FieldValue::Raw(v) => write!(f, "<{} bytes>", v.len()),
+        }
+    }
+}
+
+impl fmt::Display for DissectedField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}: ", self.id, self.name.unwrap_or("?"))?;
+        match &self.value {
+            FieldValue::List { elem_ty, elems } => write!(
+                f,
+                "{}<{}>[{}] = {}",
+                type_name(self.ty),
+                type_name(*elem_ty),
+                elems.len(),
+                self.value
+            ),
+            value => write!(f, "{} = {}", type_name(self.ty), value),
+        }
+    }
+}
+
+/// A hex dump of the sandesh, each field annotated on its first line.
+impl fmt::Display for Dissection<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} ({} bytes)", self.name, self.bytes.len())?;
+        let stop = self.bytes.len() - 1;
+        let name_len = self.fields.first().map_or(stop, |field| field.offset);
+        let name = format!("name {:?}", self.name);
+        hex_lines(f, self.bytes, 0, name_len, &name)?;
+        for field in &self.fields {
+            let annotation = field.to_string();
+            hex_lines(f, self.bytes, field.offset, field.length, &annotation)?;
+        }
+        hex_lines(f, self.bytes, stop, 1, "stop")
+    }
+}
+
+// private functions
+
+#[allow(non_upper_case_globals)]
+fn read_value(
+    r: &mut BinaryReader,
+    buf: &[u8],
+    ty: ThriftType,
+    depth: usize,
+) -> Result<FieldValue, CodecError> {
+    let value = match ty {
+        ThriftType_T_BOOL => FieldValue::Bool(bool::get(r)?),
+        ThriftType_T_BYTE => FieldValue::Byte(i8::get(r)?),
+        ThriftType_T_I16 => FieldValue::I16(i16::get(r)?),
+        ThriftType_T_I32 => FieldValue::I32(i32::get(r)?),
+        ThriftType_T_I64 => FieldValue::I64(i64::get(r)?),
+        ThriftType_T_U16 => FieldValue::U16(u16::get(r)?),
+        ThriftType_T_U32 => FieldValue::U32(u32::get(r)?),
+        ThriftType_T_U64 => FieldValue::U64(u64::get(r)?),
+        ThriftType_T_DOUBLE => FieldValue::Double(f64::from_bits(u64::get(r)?)),
+        ThriftType_T_IPV4 => FieldValue::Ipv4(Ipv4Addr::from(u32::get(r)?)),
+        ThriftType_T_STRING | ThriftType_T_XML => {
+            let mut s = String::new();
+            r.read_str_field(ThriftType_T_STRING, &mut s)?;
+            FieldValue::String(s)
+        }
+        // As deep as `skip` goes
+        ThriftType_T_LIST | ThriftType_T_SET if depth > MAX_SKIP_DEPTH => {
+            return Err(CodecError::Read(libc::EINVAL))
+        }
+        ThriftType_T_LIST | ThriftType_T_SET => {
+            let elem_ty = i8::get(r)? as u8 as ThriftType;
+            let len = i32::get(r)?;
+            if len < 0 {
+                return Err(CodecError::Read(libc::EINVAL));
+            }
+            let elems = (0..len).map(|_| read_value(r, buf, elem_ty, depth + 1));
+            FieldValue::List {
+                elem_ty,
+                elems: elems.collect::<Result<_, _>>()?,
+            }
+        }
+        ty => {
+            let start = r.position();
+            r.skip(ty)?;
+            let raw = &buf[start..r.position()];
+            match ty {
+                ThriftType_T_IPADDR => FieldValue::IpAddr(ip_addr(raw)),
+                ThriftType_T_UUID => {
+                    let mut uuid = [0; 16];
+                    uuid.copy_from_slice(raw);
+                    FieldValue::Uuid(uuid)
+                }
+                _ => FieldValue::Raw(raw.to_vec()),
+            }
+        }
+    };
+    Ok(value)
+}
+
+// `skip` only lets through an AF_INET or AF_INET6 family byte
+fn ip_addr(raw: &[u8]) -> IpAddr {
+    let (family, addr) = (raw[0] as i32, &raw[1..]);
+    if family == libc::AF_INET {
+        IpAddr::V4(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]))
+    } else {
+        let mut octets = [0; 16];
+        octets.copy_from_slice(addr);
+        IpAddr::V6(Ipv6Addr::from(octets))
+    }
+}
+
+// Up to 16 bytes a line, the annotation next to the first one
+fn hex_lines(
+    f: &mut fmt::Formatter,
+    bytes: &[u8],
+    offset: usize,
+    len: usize,
+    annotation: &str,
+) -> fmt::Result {
+    let lines = bytes[offset..offset + len].chunks(16);
+    for (i, line) in lines.enumerate() {
+        let hex: Vec<String> = line.iter().map(|b| format!("{:02x}", b)).collect();
+        let annotation = if i == 0 { annotation } else { "" };
+        let hex = hex.join(" ");
+        let line = format!("{:04x}  {:<47}  {}", offset + i * 16, hex, annotation);
+        writeln!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
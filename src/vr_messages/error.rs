@@ -11,9 +11,13 @@ pub enum CodecError {
     Read(i32),
     #[error("Unknown message type.")]
     UnknownMessageType,
+    #[error("Invalid sandesh name.")]
+    InvalidSandeshName,
+    #[error("String contains an interior nul byte.")]
+    InvalidString,
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Error)]
 pub enum OperationError {
     #[error("No such device")]
     ENODEV,
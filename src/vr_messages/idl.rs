@@ -0,0 +1,8 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// Generated at build time from priv/vr.sandesh: `SandeshType`, naming its
+// `buffer sandesh` objects, and the message structs of the objects no module
+// of vr_messages has one for, named after the object, e.g. `VrFooReq`.
+
+include!(concat!(env!("OUT_DIR"), "/vr_sandesh.rs"));
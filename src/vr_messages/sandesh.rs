@@ -6,7 +6,7 @@ use super::vr_types_binding::{
     _sandesh_op_SANDESH_OP_GET, _sandesh_op_SANDESH_OP_RESET,
     _sandesh_op_SANDESH_OP_RESPONSE, sandesh_op,
 };
-use std::convert::TryFrom;
+use std::fmt;
 
 pub const SANDESH_OP_ADD: u32 = _sandesh_op_SANDESH_OP_ADD;
 pub const SANDESH_OP_GET: u32 = _sandesh_op_SANDESH_OP_GET;
@@ -15,6 +15,8 @@ pub const SANDESH_OP_DUMP: u32 = _sandesh_op_SANDESH_OP_DUMP;
 pub const SANDESH_OP_RESPONSE: u32 = _sandesh_op_SANDESH_OP_RESPONSE;
 pub const SANDESH_OP_RESET: u32 = _sandesh_op_SANDESH_OP_RESET;
 
+/// The operation of a request. Values this crate does not know are kept as
+/// `Unknown` so they are written back unchanged.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SandeshOp {
     Add,
@@ -23,24 +25,52 @@ pub enum SandeshOp {
     Dump,
     Response,
     Reset,
+    Unknown(sandesh_op),
 }
 
 impl Default for SandeshOp {
     fn default() -> SandeshOp { SandeshOp::Add }
 }
 
-impl TryFrom<sandesh_op> for SandeshOp {
-    type Error = ();
-
-    fn try_from(v: sandesh_op) -> Result<Self, Self::Error> {
+impl From<sandesh_op> for SandeshOp {
+    fn from(v: sandesh_op) -> Self {
         match v {
-            SANDESH_OP_ADD => Ok(SandeshOp::Add),
-            SANDESH_OP_GET => Ok(SandeshOp::Get),
-            SANDESH_OP_DEL => Ok(SandeshOp::Del),
-            SANDESH_OP_DUMP => Ok(SandeshOp::Dump),
-            SANDESH_OP_RESPONSE => Ok(SandeshOp::Response),
-            SANDESH_OP_RESET => Ok(SandeshOp::Reset),
-            _ => Err(()),
+            SANDESH_OP_ADD => SandeshOp::Add,
+            SANDESH_OP_GET => SandeshOp::Get,
+            SANDESH_OP_DEL => SandeshOp::Del,
+            SANDESH_OP_DUMP => SandeshOp::Dump,
+            SANDESH_OP_RESPONSE => SandeshOp::Response,
+            SANDESH_OP_RESET => SandeshOp::Reset,
+            v => SandeshOp::Unknown(v),
+        }
+    }
+}
+
+impl From<SandeshOp> for sandesh_op {
+    fn from(op: SandeshOp) -> Self {
+        match op {
+            SandeshOp::Add => SANDESH_OP_ADD,
+            SandeshOp::Get => SANDESH_OP_GET,
+            SandeshOp::Del => SANDESH_OP_DEL,
+            SandeshOp::Dump => SANDESH_OP_DUMP,
+            SandeshOp::Response => SANDESH_OP_RESPONSE,
+            SandeshOp::Reset => SANDESH_OP_RESET,
+            SandeshOp::Unknown(v) => v,
         }
     }
 }
+
+impl fmt::Display for SandeshOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            SandeshOp::Add => "add",
+            SandeshOp::Get => "get",
+            SandeshOp::Del => "delete",
+            SandeshOp::Dump => "dump",
+            SandeshOp::Response => "response",
+            SandeshOp::Reset => "reset",
+            SandeshOp::Unknown(v) => return write!(f, "unknown({})", v),
+        };
+        f.pad(name)
+    }
+}
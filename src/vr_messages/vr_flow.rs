@@ -1,11 +1,14 @@
 // Copyright 2020 Eishun Kondoh
 // SPDX-License-Identifier: Apache-2.0
 
-use super::error::CodecError;
-use super::vr_types::VrSandesh;
-use super::vr_types_binding::{flow_op, vr_flow_req};
+use super::buffers::Encoder;
+use super::sandesh::SandeshOp;
+use super::vr_types_binding::{
+    _flow_op_FLOW_OP_FLOW_LIST, _flow_op_FLOW_OP_FLOW_SET,
+    _flow_op_FLOW_OP_FLOW_TABLE_GET, flow_op, vr_flow_req,
+};
 use crate::utils;
-use std::convert::{TryFrom, TryInto};
+use std::fmt;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 pub const VR_FLOW_RESP_FLAG_DELETED: u16 = 0x0001;
@@ -34,59 +37,168 @@ pub const VR_FLOW_FLAG_DELETE_MARKED: u16 = 0x40;
 
 pub const VR_IP6_ADDRESS_LEN: u32 = 16;
 
+pub const FLOW_OP_FLOW_SET: u32 = _flow_op_FLOW_OP_FLOW_SET;
+pub const FLOW_OP_FLOW_LIST: u32 = _flow_op_FLOW_OP_FLOW_LIST;
+pub const FLOW_OP_FLOW_TABLE_GET: u32 = _flow_op_FLOW_OP_FLOW_TABLE_GET;
+
+pub const VR_FLOW_ACTION_DROP: i16 = 0;
+pub const VR_FLOW_ACTION_HOLD: i16 = 1;
+pub const VR_FLOW_ACTION_FORWARD: i16 = 2;
+pub const VR_FLOW_ACTION_NAT: i16 = 3;
+
+pub const VR_FLOW_DR_UNKNOWN: u16 = 0x00;
+pub const VR_FLOW_DR_UNAVAILABLE_INTF: u16 = 0x01;
+pub const VR_FLOW_DR_IPV4_FWD_DIS: u16 = 0x02;
+pub const VR_FLOW_DR_UNAVAILABLE_VRF: u16 = 0x03;
+pub const VR_FLOW_DR_NO_SRC_ROUTE: u16 = 0x04;
+pub const VR_FLOW_DR_NO_DST_ROUTE: u16 = 0x05;
+pub const VR_FLOW_DR_AUDIT_ENTRY: u16 = 0x06;
+pub const VR_FLOW_DR_VRF_CHANGE: u16 = 0x07;
+pub const VR_FLOW_DR_NO_REVERSE_FLOW: u16 = 0x08;
+pub const VR_FLOW_DR_REVERSE_FLOW_CHANGE: u16 = 0x09;
+pub const VR_FLOW_DR_NAT_CHANGE: u16 = 0x0A;
+pub const VR_FLOW_DR_FLOW_LIMIT: u16 = 0x0B;
+pub const VR_FLOW_DR_LINKLOCAL_SRC_NAT: u16 = 0x0C;
+pub const VR_FLOW_DR_FAILED_VROUTER_INSTALL: u16 = 0x0D;
+pub const VR_FLOW_DR_INVALID_L2_FLOW: u16 = 0x0E;
+pub const VR_FLOW_DR_FLOW_ON_TSN: u16 = 0x0F;
+pub const VR_FLOW_DR_NO_MIRROR_ENTRY: u16 = 0x10;
+pub const VR_FLOW_DR_SAME_FLOW_RFLOW_KEY: u16 = 0x11;
+pub const VR_FLOW_DR_PORT_MAP_DROP: u16 = 0x12;
+pub const VR_FLOW_DR_NO_SRC_ROUTE_L2RPF: u16 = 0x13;
+pub const VR_FLOW_DR_FAT_FLOW_NAT_CONFLICT: u16 = 0x14;
+pub const VR_FLOW_DR_POLICY: u16 = 0x15;
+pub const VR_FLOW_DR_OUT_POLICY: u16 = 0x16;
+pub const VR_FLOW_DR_SG: u16 = 0x17;
+pub const VR_FLOW_DR_OUT_SG: u16 = 0x18;
+pub const VR_FLOW_DR_REVERSE_SG: u16 = 0x19;
+pub const VR_FLOW_DR_REVERSE_OUT_SG: u16 = 0x1A;
+pub const VR_FLOW_DR_FW_POLICY: u16 = 0x1B;
+pub const VR_FLOW_DR_OUT_FW_POLICY: u16 = 0x1C;
+pub const VR_FLOW_DR_REVERSE_FW_POLICY: u16 = 0x1D;
+pub const VR_FLOW_DR_REVERSE_OUT_FW_POLICY: u16 = 0x1E;
+pub const VR_FLOW_DR_FWAAS_POLICY: u16 = 0x1F;
+pub const VR_FLOW_DR_OUT_FWAAS_POLICY: u16 = 0x20;
+pub const VR_FLOW_DR_REVERSE_FWAAS_POLICY: u16 = 0x21;
+pub const VR_FLOW_DR_REVERSE_OUT_FWAAS_POLICY: u16 = 0x22;
+
+/// The operation of a flow request, `Unknown` for values this crate does
+/// not know.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum FlowOp {
     Set,
     List,
     Get,
+    Unknown(flow_op),
 }
 
-impl TryFrom<flow_op> for FlowOp {
-    type Error = ();
+impl Default for FlowOp {
+    fn default() -> FlowOp { FlowOp::Set }
+}
 
-    fn try_from(v: u32) -> Result<Self, Self::Error> {
+impl From<flow_op> for FlowOp {
+    fn from(v: flow_op) -> Self {
         match v {
-            x if x == FlowOp::Set as u32 => Ok(FlowOp::Set),
-            x if x == FlowOp::List as u32 => Ok(FlowOp::List),
-            x if x == FlowOp::Get as u32 => Ok(FlowOp::Get),
-            _ => Err(()),
+            FLOW_OP_FLOW_SET => FlowOp::Set,
+            FLOW_OP_FLOW_LIST => FlowOp::List,
+            FLOW_OP_FLOW_TABLE_GET => FlowOp::Get,
+            v => FlowOp::Unknown(v),
         }
     }
 }
 
-impl Default for FlowOp {
-    fn default() -> FlowOp { FlowOp::Set }
+impl From<FlowOp> for flow_op {
+    fn from(v: FlowOp) -> Self {
+        match v {
+            FlowOp::Set => FLOW_OP_FLOW_SET,
+            FlowOp::List => FLOW_OP_FLOW_LIST,
+            FlowOp::Get => FLOW_OP_FLOW_TABLE_GET,
+            FlowOp::Unknown(v) => v,
+        }
+    }
+}
+
+/// The sandesh operation a flow operation stands for: `Set` adds, `Get`
+/// gets and `List` dumps.
+impl From<FlowOp> for SandeshOp {
+    fn from(op: FlowOp) -> SandeshOp {
+        match op {
+            FlowOp::Set => SandeshOp::Add,
+            FlowOp::Get => SandeshOp::Get,
+            FlowOp::List => SandeshOp::Dump,
+            FlowOp::Unknown(v) => SandeshOp::Unknown(v),
+        }
+    }
 }
 
+impl fmt::Display for FlowOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FlowOp::Set => "set",
+            FlowOp::List => "list",
+            FlowOp::Get => "get",
+            FlowOp::Unknown(v) => return write!(f, "unknown({})", v),
+        };
+        f.pad(name)
+    }
+}
+
+/// What a flow does with its packets.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum FlowAction {
     Drop,
     Hold,
     Forward,
     Nat,
+    Unknown(i16),
+}
+
+impl Default for FlowAction {
+    fn default() -> FlowAction { FlowAction::Drop }
 }
 
-impl TryFrom<i16> for FlowAction {
-    type Error = ();
+impl From<i16> for FlowAction {
+    fn from(v: i16) -> Self {
+        match v {
+            VR_FLOW_ACTION_DROP => FlowAction::Drop,
+            VR_FLOW_ACTION_HOLD => FlowAction::Hold,
+            VR_FLOW_ACTION_FORWARD => FlowAction::Forward,
+            VR_FLOW_ACTION_NAT => FlowAction::Nat,
+            v => FlowAction::Unknown(v),
+        }
+    }
+}
 
-    fn try_from(v: i16) -> Result<Self, Self::Error> {
+impl From<FlowAction> for i16 {
+    fn from(v: FlowAction) -> Self {
         match v {
-            x if x == FlowAction::Drop as i16 => Ok(FlowAction::Drop),
-            x if x == FlowAction::Hold as i16 => Ok(FlowAction::Hold),
-            x if x == FlowAction::Forward as i16 => Ok(FlowAction::Forward),
-            x if x == FlowAction::Nat as i16 => Ok(FlowAction::Nat),
-            _ => Err(()),
+            FlowAction::Drop => VR_FLOW_ACTION_DROP,
+            FlowAction::Hold => VR_FLOW_ACTION_HOLD,
+            FlowAction::Forward => VR_FLOW_ACTION_FORWARD,
+            FlowAction::Nat => VR_FLOW_ACTION_NAT,
+            FlowAction::Unknown(v) => v,
         }
     }
 }
 
-impl Default for FlowAction {
-    fn default() -> FlowAction { FlowAction::Drop }
+impl fmt::Display for FlowAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FlowAction::Drop => "drop",
+            FlowAction::Hold => "hold",
+            FlowAction::Forward => "forward",
+            FlowAction::Nat => "nat",
+            FlowAction::Unknown(v) => return write!(f, "unknown({})", v),
+        };
+        f.pad(name)
+    }
 }
 
+/// Why a flow drops its packets, `Unknown` for values this crate does
+/// not know.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum FlowDropReason {
-    Unknown,
+    Unspecified,
     UnavailableIntf,
     Ipv4FwdDis,
     UnavailableVrf,
@@ -121,280 +233,192 @@ pub enum FlowDropReason {
     OutFwaasPolicy,
     ReverseFwaasPolicy,
     ReverseOutFwaasPolicy,
+    Unknown(u16),
 }
 
-impl TryFrom<u16> for FlowDropReason {
-    type Error = ();
+impl Default for FlowDropReason {
+    fn default() -> FlowDropReason { FlowDropReason::Unspecified }
+}
 
-    fn try_from(v: u16) -> Result<Self, Self::Error> {
+impl From<u16> for FlowDropReason {
+    fn from(v: u16) -> Self {
         match v {
-            x if x == FlowDropReason::Unknown as u16 => Ok(FlowDropReason::Unknown),
-            x if x == FlowDropReason::UnavailableIntf as u16 => {
-                Ok(FlowDropReason::UnavailableIntf)
-            }
-            x if x == FlowDropReason::Ipv4FwdDis as u16 => Ok(FlowDropReason::Ipv4FwdDis),
-            x if x == FlowDropReason::UnavailableVrf as u16 => {
-                Ok(FlowDropReason::UnavailableVrf)
-            }
-            x if x == FlowDropReason::NoSrcRoute as u16 => Ok(FlowDropReason::NoSrcRoute),
-            x if x == FlowDropReason::NoDstRoute as u16 => Ok(FlowDropReason::NoDstRoute),
-            x if x == FlowDropReason::AuditEntry as u16 => Ok(FlowDropReason::AuditEntry),
-            x if x == FlowDropReason::VrfChange as u16 => Ok(FlowDropReason::VrfChange),
-            x if x == FlowDropReason::NoReverseFlow as u16 => {
-                Ok(FlowDropReason::NoReverseFlow)
-            }
-            x if x == FlowDropReason::ReverseFlowChange as u16 => {
-                Ok(FlowDropReason::ReverseFlowChange)
-            }
-            x if x == FlowDropReason::NatChange as u16 => Ok(FlowDropReason::NatChange),
-            x if x == FlowDropReason::FlowLimit as u16 => Ok(FlowDropReason::FlowLimit),
-            x if x == FlowDropReason::LinkLocalSrcNat as u16 => {
-                Ok(FlowDropReason::LinkLocalSrcNat)
-            }
-            x if x == FlowDropReason::FailedVrouterInstall as u16 => {
-                Ok(FlowDropReason::FailedVrouterInstall)
-            }
-            x if x == FlowDropReason::InvalidL2Flow as u16 => {
-                Ok(FlowDropReason::InvalidL2Flow)
-            }
-            x if x == FlowDropReason::FlowOnTsn as u16 => Ok(FlowDropReason::FlowOnTsn),
-            x if x == FlowDropReason::NoMirrorEntry as u16 => {
-                Ok(FlowDropReason::NoMirrorEntry)
-            }
-            x if x == FlowDropReason::SameFlowRflowKey as u16 => {
-                Ok(FlowDropReason::SameFlowRflowKey)
-            }
-            x if x == FlowDropReason::PortMapDrop as u16 => {
-                Ok(FlowDropReason::PortMapDrop)
-            }
-            x if x == FlowDropReason::NoSrcRouteL2Rpf as u16 => {
-                Ok(FlowDropReason::NoSrcRouteL2Rpf)
-            }
-            x if x == FlowDropReason::FatFlowNatConflict as u16 => {
-                Ok(FlowDropReason::FatFlowNatConflict)
-            }
-            x if x == FlowDropReason::Policy as u16 => Ok(FlowDropReason::Policy),
-            x if x == FlowDropReason::OutPolicy as u16 => Ok(FlowDropReason::OutPolicy),
-            x if x == FlowDropReason::Sg as u16 => Ok(FlowDropReason::Sg),
-            x if x == FlowDropReason::OutSg as u16 => Ok(FlowDropReason::OutSg),
-            x if x == FlowDropReason::ReverseSg as u16 => Ok(FlowDropReason::ReverseSg),
-            x if x == FlowDropReason::ReverseOutSg as u16 => {
-                Ok(FlowDropReason::ReverseOutSg)
-            }
-            x if x == FlowDropReason::FwPolicy as u16 => Ok(FlowDropReason::FwPolicy),
-            x if x == FlowDropReason::OutFwPolicy as u16 => {
-                Ok(FlowDropReason::OutFwPolicy)
-            }
-            x if x == FlowDropReason::ReverseFwPolicy as u16 => {
-                Ok(FlowDropReason::ReverseFwPolicy)
-            }
-            x if x == FlowDropReason::ReverseOutFwPolicy as u16 => {
-                Ok(FlowDropReason::ReverseOutFwPolicy)
-            }
-            x if x == FlowDropReason::FwaasPolicy as u16 => {
-                Ok(FlowDropReason::FwaasPolicy)
-            }
-            x if x == FlowDropReason::OutFwaasPolicy as u16 => {
-                Ok(FlowDropReason::OutFwaasPolicy)
-            }
-            x if x == FlowDropReason::ReverseFwaasPolicy as u16 => {
-                Ok(FlowDropReason::ReverseFwaasPolicy)
-            }
-            x if x == FlowDropReason::ReverseOutFwaasPolicy as u16 => {
-                Ok(FlowDropReason::ReverseOutFwaasPolicy)
-            }
-            _ => Err(()),
+            VR_FLOW_DR_UNKNOWN => FlowDropReason::Unspecified,
+            VR_FLOW_DR_UNAVAILABLE_INTF => FlowDropReason::UnavailableIntf,
+            VR_FLOW_DR_IPV4_FWD_DIS => FlowDropReason::Ipv4FwdDis,
+            VR_FLOW_DR_UNAVAILABLE_VRF => FlowDropReason::UnavailableVrf,
+            VR_FLOW_DR_NO_SRC_ROUTE => FlowDropReason::NoSrcRoute,
+            VR_FLOW_DR_NO_DST_ROUTE => FlowDropReason::NoDstRoute,
+            VR_FLOW_DR_AUDIT_ENTRY => FlowDropReason::AuditEntry,
+            VR_FLOW_DR_VRF_CHANGE => FlowDropReason::VrfChange,
+            VR_FLOW_DR_NO_REVERSE_FLOW => FlowDropReason::NoReverseFlow,
+            VR_FLOW_DR_REVERSE_FLOW_CHANGE => FlowDropReason::ReverseFlowChange,
+            VR_FLOW_DR_NAT_CHANGE => FlowDropReason::NatChange,
+            VR_FLOW_DR_FLOW_LIMIT => FlowDropReason::FlowLimit,
+            VR_FLOW_DR_LINKLOCAL_SRC_NAT => FlowDropReason::LinkLocalSrcNat,
+            VR_FLOW_DR_FAILED_VROUTER_INSTALL => FlowDropReason::FailedVrouterInstall,
+            VR_FLOW_DR_INVALID_L2_FLOW => FlowDropReason::InvalidL2Flow,
+            VR_FLOW_DR_FLOW_ON_TSN => FlowDropReason::FlowOnTsn,
+            VR_FLOW_DR_NO_MIRROR_ENTRY => FlowDropReason::NoMirrorEntry,
+            VR_FLOW_DR_SAME_FLOW_RFLOW_KEY => FlowDropReason::SameFlowRflowKey,
+            VR_FLOW_DR_PORT_MAP_DROP => FlowDropReason::PortMapDrop,
+            VR_FLOW_DR_NO_SRC_ROUTE_L2RPF => FlowDropReason::NoSrcRouteL2Rpf,
+            VR_FLOW_DR_FAT_FLOW_NAT_CONFLICT => FlowDropReason::FatFlowNatConflict,
+            VR_FLOW_DR_POLICY => FlowDropReason::Policy,
+            VR_FLOW_DR_OUT_POLICY => FlowDropReason::OutPolicy,
+            VR_FLOW_DR_SG => FlowDropReason::Sg,
+            VR_FLOW_DR_OUT_SG => FlowDropReason::OutSg,
+            VR_FLOW_DR_REVERSE_SG => FlowDropReason::ReverseSg,
+            VR_FLOW_DR_REVERSE_OUT_SG => FlowDropReason::ReverseOutSg,
+            VR_FLOW_DR_FW_POLICY => FlowDropReason::FwPolicy,
+            VR_FLOW_DR_OUT_FW_POLICY => FlowDropReason::OutFwPolicy,
+            VR_FLOW_DR_REVERSE_FW_POLICY => FlowDropReason::ReverseFwPolicy,
+            VR_FLOW_DR_REVERSE_OUT_FW_POLICY => FlowDropReason::ReverseOutFwPolicy,
+            VR_FLOW_DR_FWAAS_POLICY => FlowDropReason::FwaasPolicy,
+            VR_FLOW_DR_OUT_FWAAS_POLICY => FlowDropReason::OutFwaasPolicy,
+            VR_FLOW_DR_REVERSE_FWAAS_POLICY => FlowDropReason::ReverseFwaasPolicy,
+            VR_FLOW_DR_REVERSE_OUT_FWAAS_POLICY => FlowDropReason::ReverseOutFwaasPolicy,
+            v => FlowDropReason::Unknown(v),
         }
     }
 }
 
-impl Default for FlowDropReason {
-    fn default() -> FlowDropReason { FlowDropReason::Unknown }
-}
-
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct FlowRequest {
-    pub op: FlowOp,
-    pub read_length: usize,
-    pub rid: i16,
-    pub index: i32,
-    pub action: FlowAction,
-    pub flags: i16,
-    pub rindex: i32,
-    pub family: i32,
-    pub flow_sip: Option<IpAddr>,
-    pub flow_dip: Option<IpAddr>,
-    pub flow_sport: u16,
-    pub flow_dport: u16,
-    pub flow_proto: i8,
-    pub flow_vrf: u16,
-    pub flow_dvrf: u16,
-    pub mirror_id: u16,
-    pub sec_mirror_id: u16,
-    pub mirror_sip: IpAddr,
-    pub mirror_sport: u16,
-    pub pcap_meta_data: Vec<u8>,
-    pub mirror_vrf: u16,
-    pub ecmp_nh_index: u32,
-    pub src_nh_index: u32,
-    pub flow_nh_id: u32,
-    pub drop_reason: FlowDropReason,
-    pub gen_id: i8,
-    pub reverse_flow_sip: Option<IpAddr>,
-    pub reverse_flow_dip: Option<IpAddr>,
-    pub reverse_flow_nh_id: u32,
-    pub reverse_flow_sport: u16,
-    pub reverse_flow_dport: u16,
-    pub qos_id: u16,
-    pub ttl: i8,
-    pub extflags: i16,
-    pub flags1: i16,
+impl From<FlowDropReason> for u16 {
+    fn from(v: FlowDropReason) -> Self {
+        match v {
+            FlowDropReason::Unspecified => VR_FLOW_DR_UNKNOWN,
+            FlowDropReason::UnavailableIntf => VR_FLOW_DR_UNAVAILABLE_INTF,
+            FlowDropReason::Ipv4FwdDis => VR_FLOW_DR_IPV4_FWD_DIS,
+            FlowDropReason::UnavailableVrf => VR_FLOW_DR_UNAVAILABLE_VRF,
+            FlowDropReason::NoSrcRoute => VR_FLOW_DR_NO_SRC_ROUTE,
+            FlowDropReason::NoDstRoute => VR_FLOW_DR_NO_DST_ROUTE,
+            FlowDropReason::AuditEntry => VR_FLOW_DR_AUDIT_ENTRY,
+            FlowDropReason::VrfChange => VR_FLOW_DR_VRF_CHANGE,
+            FlowDropReason::NoReverseFlow => VR_FLOW_DR_NO_REVERSE_FLOW,
+            FlowDropReason::ReverseFlowChange => VR_FLOW_DR_REVERSE_FLOW_CHANGE,
+            FlowDropReason::NatChange => VR_FLOW_DR_NAT_CHANGE,
+            FlowDropReason::FlowLimit => VR_FLOW_DR_FLOW_LIMIT,
+            FlowDropReason::LinkLocalSrcNat => VR_FLOW_DR_LINKLOCAL_SRC_NAT,
+            FlowDropReason::FailedVrouterInstall => VR_FLOW_DR_FAILED_VROUTER_INSTALL,
+            FlowDropReason::InvalidL2Flow => VR_FLOW_DR_INVALID_L2_FLOW,
+            FlowDropReason::FlowOnTsn => VR_FLOW_DR_FLOW_ON_TSN,
+            FlowDropReason::NoMirrorEntry => VR_FLOW_DR_NO_MIRROR_ENTRY,
+            FlowDropReason::SameFlowRflowKey => VR_FLOW_DR_SAME_FLOW_RFLOW_KEY,
+            FlowDropReason::PortMapDrop => VR_FLOW_DR_PORT_MAP_DROP,
+            FlowDropReason::NoSrcRouteL2Rpf => VR_FLOW_DR_NO_SRC_ROUTE_L2RPF,
+            FlowDropReason::FatFlowNatConflict => VR_FLOW_DR_FAT_FLOW_NAT_CONFLICT,
+            FlowDropReason::Policy => VR_FLOW_DR_POLICY,
+            FlowDropReason::OutPolicy => VR_FLOW_DR_OUT_POLICY,
+            FlowDropReason::Sg => VR_FLOW_DR_SG,
+            FlowDropReason::OutSg => VR_FLOW_DR_OUT_SG,
+            FlowDropReason::ReverseSg => VR_FLOW_DR_REVERSE_SG,
+            FlowDropReason::ReverseOutSg => VR_FLOW_DR_REVERSE_OUT_SG,
+            FlowDropReason::FwPolicy => VR_FLOW_DR_FW_POLICY,
+            FlowDropReason::OutFwPolicy => VR_FLOW_DR_OUT_FW_POLICY,
+            FlowDropReason::ReverseFwPolicy => VR_FLOW_DR_REVERSE_FW_POLICY,
+            FlowDropReason::ReverseOutFwPolicy => VR_FLOW_DR_REVERSE_OUT_FW_POLICY,
+            FlowDropReason::FwaasPolicy => VR_FLOW_DR_FWAAS_POLICY,
+            FlowDropReason::OutFwaasPolicy => VR_FLOW_DR_OUT_FWAAS_POLICY,
+            FlowDropReason::ReverseFwaasPolicy => VR_FLOW_DR_REVERSE_FWAAS_POLICY,
+            FlowDropReason::ReverseOutFwaasPolicy => VR_FLOW_DR_REVERSE_OUT_FWAAS_POLICY,
+            FlowDropReason::Unknown(v) => v,
+        }
+    }
 }
 
-impl Default for FlowRequest {
-    fn default() -> FlowRequest {
-        FlowRequest {
-            op: FlowOp::Set,
-            read_length: 0,
-            rid: 0,
-            index: 0,
-            action: FlowAction::Drop,
-            flags: 0,
-            rindex: -1,
-            family: 0,
-            flow_sip: None,
-            flow_dip: None,
-            flow_sport: 0,
-            flow_dport: 0,
-            flow_proto: 0,
-            flow_vrf: 0,
-            flow_dvrf: 0,
-            mirror_id: 0,
-            sec_mirror_id: 0,
-            mirror_sip: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
-            mirror_sport: 0,
-            pcap_meta_data: vec![],
-            mirror_vrf: 0,
-            ecmp_nh_index: 0,
-            src_nh_index: 0,
-            flow_nh_id: 0,
-            drop_reason: FlowDropReason::Unknown,
-            gen_id: 0,
-            reverse_flow_sip: None,
-            reverse_flow_dip: None,
-            reverse_flow_nh_id: 0,
-            reverse_flow_sport: 0,
-            reverse_flow_dport: 0,
-            qos_id: 0,
-            ttl: 0,
-            extflags: 0,
-            flags1: 0,
-        }
+impl fmt::Display for FlowDropReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            FlowDropReason::Unspecified => "unknown",
+            FlowDropReason::UnavailableIntf => "unavailable interface",
+            FlowDropReason::Ipv4FwdDis => "ipv4 forwarding disabled",
+            FlowDropReason::UnavailableVrf => "unavailable vrf",
+            FlowDropReason::NoSrcRoute => "no source route",
+            FlowDropReason::NoDstRoute => "no destination route",
+            FlowDropReason::AuditEntry => "audit entry",
+            FlowDropReason::VrfChange => "vrf change",
+            FlowDropReason::NoReverseFlow => "no reverse flow",
+            FlowDropReason::ReverseFlowChange => "reverse flow change",
+            FlowDropReason::NatChange => "nat change",
+            FlowDropReason::FlowLimit => "flow limit",
+            FlowDropReason::LinkLocalSrcNat => "link local source nat",
+            FlowDropReason::FailedVrouterInstall => "failed vrouter install",
+            FlowDropReason::InvalidL2Flow => "invalid l2 flow",
+            FlowDropReason::FlowOnTsn => "flow on tsn",
+            FlowDropReason::NoMirrorEntry => "no mirror entry",
+            FlowDropReason::SameFlowRflowKey => "same flow and reverse flow key",
+            FlowDropReason::PortMapDrop => "port map drop",
+            FlowDropReason::NoSrcRouteL2Rpf => "no source route (l2 rpf)",
+            FlowDropReason::FatFlowNatConflict => "fat flow nat conflict",
+            FlowDropReason::Policy => "policy",
+            FlowDropReason::OutPolicy => "out policy",
+            FlowDropReason::Sg => "security group",
+            FlowDropReason::OutSg => "out security group",
+            FlowDropReason::ReverseSg => "reverse security group",
+            FlowDropReason::ReverseOutSg => "reverse out security group",
+            FlowDropReason::FwPolicy => "firewall policy",
+            FlowDropReason::OutFwPolicy => "out firewall policy",
+            FlowDropReason::ReverseFwPolicy => "reverse firewall policy",
+            FlowDropReason::ReverseOutFwPolicy => "reverse out firewall policy",
+            FlowDropReason::FwaasPolicy => "fwaas policy",
+            FlowDropReason::OutFwaasPolicy => "out fwaas policy",
+            FlowDropReason::ReverseFwaasPolicy => "reverse fwaas policy",
+            FlowDropReason::ReverseOutFwaasPolicy => "reverse out fwaas policy",
+            FlowDropReason::Unknown(v) => return write!(f, "unknown({})", v),
+        };
+        f.pad(name)
     }
 }
 
+include!(concat!(env!("OUT_DIR"), "/vr_flow_req.rs"));
+
 impl FlowRequest {
-    pub fn write(&self) -> Result<Vec<u8>, CodecError> {
-        let mut encoder: vr_flow_req = vr_flow_req::new();
-        encoder.fr_op = self.op as u32;
-        encoder.fr_rid = self.rid;
-        encoder.fr_index = self.index;
-        encoder.fr_action = self.action as i16;
-        encoder.fr_rindex = self.rindex;
-        encoder.fr_family = self.family;
-        let flow_sip = Self::write_ip(&self.flow_sip);
-        encoder.fr_flow_sip_u = flow_sip.0;
-        encoder.fr_flow_sip_l = flow_sip.1;
-        let flow_dip = Self::write_ip(&self.flow_dip);
-        encoder.fr_flow_dip_u = flow_dip.0;
-        encoder.fr_flow_dip_l = flow_dip.1;
-        encoder.fr_flow_sport = self.flow_sport;
-        encoder.fr_flow_dport = self.flow_dport;
-        encoder.fr_flow_proto = self.flow_proto;
-        encoder.fr_flow_vrf = self.flow_vrf;
-        encoder.fr_flow_dvrf = self.flow_dvrf;
-        encoder.fr_mir_id = self.mirror_id;
-        encoder.fr_sec_mir_id = self.sec_mirror_id;
-        let mirror_sip = Self::write_ip(&Some(self.mirror_sip)).1 as u32;
-        encoder.fr_mir_sip = mirror_sip;
-        encoder.fr_mir_sport = self.mirror_sport;
-        encoder.fr_pcap_meta_data = utils::into_mut_ptr(&self.pcap_meta_data) as *mut i8;
-        encoder.fr_pcap_meta_data_size = self.pcap_meta_data.len() as u32;
-        encoder.fr_mir_vrf = self.mirror_vrf;
-        encoder.fr_ecmp_nh_index = self.ecmp_nh_index;
-        encoder.fr_src_nh_index = self.src_nh_index;
-        encoder.fr_flow_nh_id = self.flow_nh_id;
-        encoder.fr_drop_reason = self.drop_reason as u16;
-        encoder.fr_gen_id = self.gen_id;
-        let rflow_sip = Self::write_ip(&self.reverse_flow_sip);
-        encoder.fr_rflow_sip_u = rflow_sip.0;
-        encoder.fr_rflow_sip_l = rflow_sip.1;
-        let rflow_dip = Self::write_ip(&self.reverse_flow_dip);
-        encoder.fr_rflow_dip_u = rflow_dip.0;
-        encoder.fr_rflow_dip_l = rflow_dip.1;
-        encoder.fr_rflow_nh_id = self.reverse_flow_nh_id;
-        encoder.fr_rflow_sport = self.reverse_flow_sport;
-        encoder.fr_rflow_dport = self.reverse_flow_dport;
-        encoder.fr_qos_id = self.qos_id;
-        encoder.fr_ttl = self.ttl;
-        encoder.fr_extflags = self.extflags;
-        encoder.fr_flags1 = self.flags1;
-        encoder.write()
+    fn write_flow_sip(&self, fr: &mut Encoder<vr_flow_req>) {
+        let (ip_u, ip_l) = Self::write_ip(&self.flow_sip);
+        fr.fr_flow_sip_u = ip_u;
+        fr.fr_flow_sip_l = ip_l;
+    }
+
+    fn read_flow_sip(fr: &vr_flow_req) -> Option<IpAddr> {
+        Self::read_ip(fr.fr_family, fr.fr_flow_sip_u, fr.fr_flow_sip_l)
+    }
+
+    fn write_flow_dip(&self, fr: &mut Encoder<vr_flow_req>) {
+        let (ip_u, ip_l) = Self::write_ip(&self.flow_dip);
+        fr.fr_flow_dip_u = ip_u;
+        fr.fr_flow_dip_l = ip_l;
+    }
+
+    fn read_flow_dip(fr: &vr_flow_req) -> Option<IpAddr> {
+        Self::read_ip(fr.fr_family, fr.fr_flow_dip_u, fr.fr_flow_dip_l)
+    }
+
+    fn write_mirror_sip(&self, fr: &mut Encoder<vr_flow_req>) {
+        fr.fr_mir_sip = Self::write_ip(&Some(self.mirror_sip)).1 as u32;
+    }
+
+    fn read_mirror_sip(fr: &vr_flow_req) -> IpAddr {
+        Self::read_ip4(fr.fr_mir_sip as u128)
+    }
+
+    fn write_reverse_flow_sip(&self, fr: &mut Encoder<vr_flow_req>) {
+        let (ip_u, ip_l) = Self::write_ip(&self.reverse_flow_sip);
+        fr.fr_rflow_sip_u = ip_u;
+        fr.fr_rflow_sip_l = ip_l;
+    }
+
+    fn read_reverse_flow_sip(fr: &vr_flow_req) -> Option<IpAddr> {
+        Self::read_ip(fr.fr_family, fr.fr_rflow_sip_u, fr.fr_rflow_sip_l)
+    }
+
+    fn write_reverse_flow_dip(&self, fr: &mut Encoder<vr_flow_req>) {
+        let (ip_u, ip_l) = Self::write_ip(&self.reverse_flow_dip);
+        fr.fr_rflow_dip_u = ip_u;
+        fr.fr_rflow_dip_l = ip_l;
     }
 
-    pub fn read(buf: Vec<u8>) -> Result<FlowRequest, CodecError> {
-        let decoder: vr_flow_req = vr_flow_req::new();
-        let rxfer = decoder.read(&buf)?;
-        let mut fr: FlowRequest = FlowRequest::default();
-        fr.read_length = rxfer as usize;
-        fr.op = decoder.fr_op.try_into().unwrap();
-        fr.rid = decoder.fr_rid;
-        fr.index = decoder.fr_index;
-        fr.action = decoder.fr_action.try_into().unwrap();
-        fr.rindex = decoder.fr_rindex;
-        fr.family = decoder.fr_family;
-        fr.flow_sip = Self::read_ip(
-            decoder.fr_family,
-            decoder.fr_flow_sip_u,
-            decoder.fr_flow_sip_l,
-        );
-        fr.flow_dip = Self::read_ip(
-            decoder.fr_family,
-            decoder.fr_flow_dip_u,
-            decoder.fr_flow_dip_l,
-        );
-        fr.flow_sport = decoder.fr_flow_sport;
-        fr.flow_dport = decoder.fr_flow_dport;
-        fr.flow_proto = decoder.fr_flow_proto;
-        fr.flow_vrf = decoder.fr_flow_vrf;
-        fr.flow_dvrf = decoder.fr_flow_dvrf;
-        fr.mirror_id = decoder.fr_mir_id;
-        fr.sec_mirror_id = decoder.fr_sec_mir_id;
-        fr.mirror_sip = Self::read_ip4(decoder.fr_mir_sip as u128);
-        fr.mirror_sport = decoder.fr_mir_sport;
-        fr.ecmp_nh_index = decoder.fr_ecmp_nh_index;
-        fr.src_nh_index = decoder.fr_src_nh_index;
-        fr.flow_nh_id = decoder.fr_flow_nh_id;
-        fr.drop_reason = decoder.fr_drop_reason.try_into().unwrap();
-        fr.gen_id = decoder.fr_gen_id;
-        fr.reverse_flow_sip = Self::read_ip(
-            decoder.fr_family,
-            decoder.fr_rflow_sip_u,
-            decoder.fr_rflow_sip_l,
-        );
-        fr.reverse_flow_dip = Self::read_ip(
-            decoder.fr_family,
-            decoder.fr_rflow_dip_u,
-            decoder.fr_rflow_dip_l,
-        );
-        fr.reverse_flow_nh_id = decoder.fr_rflow_nh_id;
-        fr.reverse_flow_sport = decoder.fr_rflow_sport;
-        fr.reverse_flow_dport = decoder.fr_rflow_dport;
-        fr.qos_id = decoder.fr_qos_id;
-        fr.ttl = decoder.fr_ttl;
-        fr.extflags = decoder.fr_extflags;
-        fr.flags1 = decoder.fr_flags1;
-        Ok(fr)
+    fn read_reverse_flow_dip(fr: &vr_flow_req) -> Option<IpAddr> {
+        Self::read_ip(fr.fr_family, fr.fr_rflow_dip_u, fr.fr_rflow_dip_l)
     }
 
     fn write_ip(ip: &Option<IpAddr>) -> (u64, u64) {
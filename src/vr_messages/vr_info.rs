@@ -0,0 +1,145 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+use super::sandesh::SandeshOp;
+use super::{Message, MessageType};
+use crate::genetlink::{MessageHandleError, Transport, VrouterSession};
+use std::fmt;
+
+pub const INFO_BOND: i16 = 0;
+pub const INFO_LACP: i16 = 1;
+pub const INFO_MEMPOOL: i16 = 2;
+pub const INFO_STATS: i16 = 3;
+pub const INFO_XSTATS: i16 = 4;
+pub const INFO_LCORE: i16 = 5;
+pub const INFO_APP: i16 = 6;
+
+/// The diagnostics a `vr_info_req` asks for, as `dpdkinfo` names them.
+/// `Unknown` for values this crate does not know.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum InfoMsg {
+    #[default]
+    Bond,
+    Lacp,
+    Mempool,
+    Stats,
+    Xstats,
+    Lcore,
+    App,
+    Unknown(i16),
+}
+
+impl From<i16> for InfoMsg {
+    fn from(v: i16) -> Self {
+        match v {
+            INFO_BOND => InfoMsg::Bond,
+            INFO_LACP => InfoMsg::Lacp,
+            INFO_MEMPOOL => InfoMsg::Mempool,
+            INFO_STATS => InfoMsg::Stats,
+            INFO_XSTATS => InfoMsg::Xstats,
+            INFO_LCORE => InfoMsg::Lcore,
+            INFO_APP => InfoMsg::App,
+            v => InfoMsg::Unknown(v),
+        }
+    }
+}
+
+impl From<InfoMsg> for i16 {
+    fn from(v: InfoMsg) -> Self {
+        match v {
+            InfoMsg::Bond => INFO_BOND,
+            InfoMsg::Lacp => INFO_LACP,
+            InfoMsg::Mempool => INFO_MEMPOOL,
+            InfoMsg::Stats => INFO_STATS,
+            InfoMsg::Xstats => INFO_XSTATS,
+            InfoMsg::Lcore => INFO_LCORE,
+            InfoMsg::App => INFO_APP,
+            InfoMsg::Unknown(v) => v,
+        }
+    }
+}
+
+impl fmt::Display for InfoMsg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            InfoMsg::Bond => "bond",
+            InfoMsg::Lacp => "lacp",
+            InfoMsg::Mempool => "mempool",
+            InfoMsg::Stats => "stats",
+            InfoMsg::Xstats => "xstats",
+            InfoMsg::Lcore => "lcore",
+            InfoMsg::App => "app",
+            InfoMsg::Unknown(v) => return write!(f, "unknown({})", v),
+        };
+        f.pad(name)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/vr_info_req.rs"));
+
+impl InfoRequest {
+    /// A dump of the `msginfo` diagnostics, from their first chunk.
+    pub fn dump(msginfo: InfoMsg) -> InfoRequest {
+        InfoRequest {
+            op: SandeshOp::Dump,
+            msginfo,
+            ..InfoRequest::default()
+        }
+    }
+
+    /// The text of this chunk of `proc_info`, up to its nul terminator.
+    pub fn proc_info_text(&self) -> String {
+        String::from_utf8_lossy(&self.proc_info_bytes()).into_owned()
+    }
+
+    /// The request for the chunk following `reply`, the answer to this one.
+    /// `None` once the vrouter has no more text, or no longer moves the
+    /// marker forward.
+    pub fn next_chunk(&self, reply: &InfoRequest) -> Option<InfoRequest> {
+        if reply.proc_info.is_empty() || reply.marker <= self.marker {
+            return None;
+        }
+        let mut next = self.clone();
+        next.marker = reply.marker;
+        next.buff_table_id = reply.buff_table_id;
+        Some(next)
+    }
+
+    /// Sends the request, then one for each chunk following its reply, and
+    /// joins the text of all of them. A character split across two chunks
+    /// comes out whole. Fails if a reply holds no vr_info_req.
+    pub fn collect_with(
+        &self,
+        transport: &dyn Transport,
+    ) -> Result<String, MessageHandleError> {
+        let mut text = Vec::new();
+        let mut request = self.clone();
+        loop {
+            let replies = Message::InfoRequest(request.clone()).send_with(transport)?;
+            let reply = replies.into_iter().find_map(|message| match message {
+                Message::InfoRequest(vdu) => Some(vdu),
+                _ => None,
+            });
+            let missing = MessageHandleError::MissingReply(MessageType::InfoRequest);
+            let reply = reply.ok_or(missing)?;
+            text.extend(reply.proc_info_bytes());
+            request = match request.next_chunk(&reply) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(String::from_utf8_lossy(&text).into_owned())
+    }
+
+    /// Same as `collect_with`, with the vrouter of this host.
+    pub fn collect(&self) -> Result<String, MessageHandleError> {
+        self.collect_with(&VrouterSession::open()?)
+    }
+
+    // private functions
+
+    fn proc_info_bytes(&self) -> Vec<u8> {
+        let text = self.proc_info.iter().map(|&c| c as u8);
+        text.take_while(|&c| c != 0).collect()
+    }
+}
@@ -1,107 +1,41 @@
 // Copyright 2020 Eishun Kondoh
 // SPDX-License-Identifier: Apache-2.0
 
-use super::error::CodecError;
-use super::sandesh::SandeshOp;
-use super::vr_types::VrSandesh;
+use super::buffers::{self, Encoder};
 use super::vr_types_binding::vr_route_req;
 use crate::utils;
 use eui48::MacAddress;
-use std::convert::TryInto;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub struct RouteRequest {
-    pub op: SandeshOp,
-    pub read_length: usize,
-    pub vrf_id: i32,
-    pub family: i32,
-    pub prefix: Option<IpAddr>,
-    pub prefix_len: i32,
-    pub rid: i16,
-    pub label_flags: i16,
-    pub label: i32,
-    pub nh_id: i32,
-    pub marker: Option<IpAddr>,
-    pub marker_prefix_len: i32,
-    pub mac: MacAddress,
-    pub replace_prefix_len: i32,
-    pub index: i32,
-}
+include!(concat!(env!("OUT_DIR"), "/vr_route_req.rs"));
 
-impl Default for RouteRequest {
-    fn default() -> RouteRequest {
-        RouteRequest {
-            op: SandeshOp::Add,
-            read_length: 0,
-            vrf_id: 0,
-            family: 0,
-            prefix: None,
-            prefix_len: 0,
-            rid: 0,
-            label_flags: 0,
-            label: 0,
-            nh_id: 0,
-            marker: None,
-            marker_prefix_len: 0,
-            mac: MacAddress::nil(),
-            replace_prefix_len: 0,
-            index: 0,
-        }
+impl RouteRequest {
+    fn write_prefix(&self, rtr: &mut Encoder<vr_route_req>) {
+        rtr.rtr_prefix = rtr.list(&Self::write_ip(&self.prefix));
+        rtr.rtr_prefix_size = Self::prefix_size(self.prefix);
     }
-}
 
-impl RouteRequest {
-    pub fn write(&self) -> Result<Vec<u8>, CodecError> {
-        let mut encoder: vr_route_req = vr_route_req::new();
-        encoder.h_op = self.op as u32;
-        encoder.rtr_vrf_id = self.vrf_id;
-        encoder.rtr_family = self.family;
-        encoder.rtr_prefix = Self::write_ip(&self.prefix);
-        encoder.rtr_prefix_size = Self::prefix_size(self.prefix);
-        encoder.rtr_prefix_len = self.prefix_len;
-        encoder.rtr_rid = self.rid;
-        encoder.rtr_label_flags = self.label_flags;
-        encoder.rtr_label = self.label;
-        encoder.rtr_nh_id = self.nh_id;
-        encoder.rtr_marker = Self::write_ip(&self.marker);
-        encoder.rtr_marker_size = Self::prefix_size(self.marker);
-        encoder.rtr_marker_plen = self.marker_prefix_len;
-        encoder.rtr_mac = utils::write_mac(self.mac);
-        encoder.rtr_mac_size = libc::ETH_ALEN as u32;
-        encoder.rtr_replace_plen = self.replace_prefix_len;
-        encoder.rtr_index = self.index;
-        encoder.write()
+    fn read_prefix(rtr: &vr_route_req) -> Option<IpAddr> {
+        Self::read_ip(rtr.rtr_family, rtr.rtr_prefix, rtr.rtr_prefix_size)
+    }
+
+    fn write_marker(&self, rtr: &mut Encoder<vr_route_req>) {
+        rtr.rtr_marker = rtr.list(&Self::write_ip(&self.marker));
+        rtr.rtr_marker_size = Self::prefix_size(self.marker);
     }
 
-    pub fn read(buf: Vec<u8>) -> Result<RouteRequest, CodecError> {
-        let decoder: vr_route_req = vr_route_req::new();
-        let rxfer = decoder.read(&buf)?;
-        let mut rtr: RouteRequest = RouteRequest::default();
-        rtr.read_length = rxfer as usize;
-        rtr.op = decoder.h_op.try_into().unwrap();
-        rtr.vrf_id = decoder.rtr_vrf_id;
-        rtr.family = decoder.rtr_family;
-        rtr.prefix = Self::read_ip(
-            decoder.rtr_family,
-            decoder.rtr_prefix,
-            decoder.rtr_prefix_size,
-        );
-        rtr.prefix_len = decoder.rtr_prefix_len;
-        rtr.rid = decoder.rtr_rid;
-        rtr.label_flags = decoder.rtr_label_flags;
-        rtr.label = decoder.rtr_label;
-        rtr.nh_id = decoder.rtr_nh_id;
-        rtr.marker = Self::read_ip(
-            decoder.rtr_family,
-            decoder.rtr_marker,
-            decoder.rtr_marker_size,
-        );
-        rtr.marker_prefix_len = decoder.rtr_marker_plen;
-        rtr.mac = utils::read_mac_addr(decoder.rtr_mac, decoder.rtr_mac_size);
-        rtr.replace_prefix_len = decoder.rtr_replace_plen;
-        rtr.index = decoder.rtr_index;
-        Ok(rtr)
+    fn read_marker(rtr: &vr_route_req) -> Option<IpAddr> {
+        Self::read_ip(rtr.rtr_family, rtr.rtr_marker, rtr.rtr_marker_size)
+    }
+
+    // Sent even when nil, unlike the MAC addresses of other objects
+    fn write_mac(&self, rtr: &mut Encoder<vr_route_req>) {
+        rtr.rtr_mac = rtr.list(&utils::write_mac(self.mac));
+        rtr.rtr_mac_size = libc::ETH_ALEN as u32;
+    }
+
+    fn read_mac(rtr: &vr_route_req) -> MacAddress {
+        utils::read_mac_addr(rtr.rtr_mac, rtr.rtr_mac_size)
     }
 
     fn prefix_size(ip: Option<IpAddr>) -> u32 {
@@ -112,13 +46,12 @@ impl RouteRequest {
         }
     }
 
-    fn write_ip(ip: &Option<IpAddr>) -> *mut i8 {
-        let v: Vec<i8> = match ip {
+    fn write_ip(ip: &Option<IpAddr>) -> Vec<i8> {
+        match ip {
             Some(IpAddr::V4(ip4)) => Self::write_ip4(*ip4),
             Some(IpAddr::V6(ip6)) => Self::write_ip6(*ip6),
             None => vec![],
-        };
-        utils::into_mut_ptr(&v)
+        }
     }
 
     fn read_ip(family: i32, ptr: *mut i8, size: u32) -> Option<IpAddr> {
@@ -141,7 +74,7 @@ impl RouteRequest {
     }
 
     fn read_ip6(ptr: *mut i8) -> Ipv6Addr {
-        let octets = utils::free_buf(ptr, 16);
+        let octets = buffers::read_list(ptr, 16);
         Ipv6Addr::from(
             ((octets[0] as u128) << 120)
                 | ((octets[1] as u128) << 112)
@@ -174,7 +107,7 @@ impl RouteRequest {
     }
 
     fn read_ip4(ptr: *mut i8) -> Ipv4Addr {
-        let octets = utils::free_buf(ptr, 4);
+        let octets = buffers::read_list(ptr, 4);
         Ipv4Addr::from(
             ((octets[0] as u32) << 24)
                 | ((octets[1] as u32) << 16)
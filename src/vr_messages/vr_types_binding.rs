@@ -1,5 +1,7 @@
 /* automatically generated by rust-bindgen */
 
+include!(concat!(env!("OUT_DIR"), "/vr_types_structs.rs"));
+
 #[repr(C)]
 #[derive(Default)]
 pub struct __IncompleteArrayField<T>(::std::marker::PhantomData<T>, [T; 0]);
@@ -3653,19 +3655,6 @@ extern "C" {
         error: *mut ::std::os::raw::c_int,
     ) -> i32;
 }
-pub const _sandesh_op_SANDESH_OP_ADD: _sandesh_op = 0;
-pub const _sandesh_op_SANDESH_OP_GET: _sandesh_op = 1;
-pub const _sandesh_op_SANDESH_OP_DEL: _sandesh_op = 2;
-pub const _sandesh_op_SANDESH_OP_DUMP: _sandesh_op = 3;
-pub const _sandesh_op_SANDESH_OP_RESPONSE: _sandesh_op = 4;
-pub const _sandesh_op_SANDESH_OP_RESET: _sandesh_op = 5;
-pub type _sandesh_op = u32;
-pub use self::_sandesh_op as sandesh_op;
-pub const _flow_op_FLOW_OP_FLOW_SET: _flow_op = 0;
-pub const _flow_op_FLOW_OP_FLOW_LIST: _flow_op = 1;
-pub const _flow_op_FLOW_OP_FLOW_TABLE_GET: _flow_op = 2;
-pub type _flow_op = u32;
-pub use self::_flow_op as flow_op;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _sandesh_hdr {
@@ -3708,1930 +3697,6 @@ extern "C" {
 extern "C" {
     pub fn sandesh_hdr_free(fsandesh_hdr: *mut sandesh_hdr);
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_nexthop_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub nhr_type: i8,
-    pub __isset_nhr_type: u_int8_t,
-    pub nhr_family: i8,
-    pub __isset_nhr_family: u_int8_t,
-    pub nhr_id: i32,
-    pub __isset_nhr_id: u_int8_t,
-    pub nhr_rid: i32,
-    pub __isset_nhr_rid: u_int8_t,
-    pub nhr_encap_oif_id: i32,
-    pub __isset_nhr_encap_oif_id: u_int8_t,
-    pub nhr_encap_len: i32,
-    pub __isset_nhr_encap_len: u_int8_t,
-    pub nhr_encap_family: i32,
-    pub __isset_nhr_encap_family: u_int8_t,
-    pub nhr_vrf: i32,
-    pub __isset_nhr_vrf: u_int8_t,
-    pub nhr_tun_sip: u32,
-    pub __isset_nhr_tun_sip: u_int8_t,
-    pub nhr_tun_dip: u32,
-    pub __isset_nhr_tun_dip: u_int8_t,
-    pub nhr_tun_sport: i16,
-    pub __isset_nhr_tun_sport: u_int8_t,
-    pub nhr_tun_dport: i16,
-    pub __isset_nhr_tun_dport: u_int8_t,
-    pub nhr_ref_cnt: i32,
-    pub __isset_nhr_ref_cnt: u_int8_t,
-    pub nhr_marker: i32,
-    pub __isset_nhr_marker: u_int8_t,
-    pub nhr_flags: u32,
-    pub __isset_nhr_flags: u_int8_t,
-    pub nhr_encap: *mut i8,
-    pub nhr_encap_size: u_int32_t,
-    pub __isset_nhr_encap: u_int8_t,
-    pub nhr_nh_list: *mut i32,
-    pub nhr_nh_list_size: u_int32_t,
-    pub __isset_nhr_nh_list: u_int8_t,
-    pub nhr_label_list: *mut i32,
-    pub nhr_label_list_size: u_int32_t,
-    pub __isset_nhr_label_list: u_int8_t,
-    pub nhr_nh_count: i16,
-    pub __isset_nhr_nh_count: u_int8_t,
-    pub nhr_tun_sip6: *mut i8,
-    pub nhr_tun_sip6_size: u_int32_t,
-    pub __isset_nhr_tun_sip6: u_int8_t,
-    pub nhr_tun_dip6: *mut i8,
-    pub nhr_tun_dip6_size: u_int32_t,
-    pub __isset_nhr_tun_dip6: u_int8_t,
-    pub nhr_ecmp_config_hash: i8,
-    pub __isset_nhr_ecmp_config_hash: u_int8_t,
-    pub nhr_pbb_mac: *mut i8,
-    pub nhr_pbb_mac_size: u_int32_t,
-    pub __isset_nhr_pbb_mac: u_int8_t,
-    pub nhr_encap_crypt_oif_id: i32,
-    pub __isset_nhr_encap_crypt_oif_id: u_int8_t,
-    pub nhr_crypt_traffic: i32,
-    pub __isset_nhr_crypt_traffic: u_int8_t,
-    pub nhr_crypt_path_available: i32,
-    pub __isset_nhr_crypt_path_available: u_int8_t,
-    pub nhr_rw_dst_mac: *mut i8,
-    pub nhr_rw_dst_mac_size: u_int32_t,
-    pub __isset_nhr_rw_dst_mac: u_int8_t,
-    pub nhr_transport_label: u32,
-    pub __isset_nhr_transport_label: u_int8_t,
-}
-pub type vr_nexthop_req = _vr_nexthop_req;
-extern "C" {
-    pub fn vr_nexthop_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_nexthop_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_nexthop_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_nexthop_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_nexthop_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_nexthop_req_process(pvr_nexthop_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_interface_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vifr_core: u32,
-    pub __isset_vifr_core: u_int8_t,
-    pub vifr_type: i32,
-    pub __isset_vifr_type: u_int8_t,
-    pub vifr_flags: i32,
-    pub __isset_vifr_flags: u_int8_t,
-    pub vifr_vrf: i32,
-    pub __isset_vifr_vrf: u_int8_t,
-    pub vifr_idx: i32,
-    pub __isset_vifr_idx: u_int8_t,
-    pub vifr_rid: i32,
-    pub __isset_vifr_rid: u_int8_t,
-    pub vifr_os_idx: i32,
-    pub __isset_vifr_os_idx: u_int8_t,
-    pub vifr_mtu: i32,
-    pub __isset_vifr_mtu: u_int8_t,
-    pub vifr_name: *mut ::std::os::raw::c_char,
-    pub __isset_vifr_name: u_int8_t,
-    pub vifr_ibytes: i64,
-    pub __isset_vifr_ibytes: u_int8_t,
-    pub vifr_ipackets: i64,
-    pub __isset_vifr_ipackets: u_int8_t,
-    pub vifr_ierrors: i64,
-    pub __isset_vifr_ierrors: u_int8_t,
-    pub vifr_obytes: i64,
-    pub __isset_vifr_obytes: u_int8_t,
-    pub vifr_opackets: i64,
-    pub __isset_vifr_opackets: u_int8_t,
-    pub vifr_oerrors: i64,
-    pub __isset_vifr_oerrors: u_int8_t,
-    pub vifr_queue_ipackets: i64,
-    pub __isset_vifr_queue_ipackets: u_int8_t,
-    pub vifr_queue_ierrors: i64,
-    pub __isset_vifr_queue_ierrors: u_int8_t,
-    pub vifr_queue_ierrors_to_lcore: *mut i64,
-    pub vifr_queue_ierrors_to_lcore_size: u_int32_t,
-    pub __isset_vifr_queue_ierrors_to_lcore: u_int8_t,
-    pub vifr_queue_opackets: i64,
-    pub __isset_vifr_queue_opackets: u_int8_t,
-    pub vifr_queue_oerrors: i64,
-    pub __isset_vifr_queue_oerrors: u_int8_t,
-    pub vifr_port_ipackets: i64,
-    pub __isset_vifr_port_ipackets: u_int8_t,
-    pub vifr_port_ierrors: i64,
-    pub __isset_vifr_port_ierrors: u_int8_t,
-    pub vifr_port_isyscalls: i64,
-    pub __isset_vifr_port_isyscalls: u_int8_t,
-    pub vifr_port_inombufs: i64,
-    pub __isset_vifr_port_inombufs: u_int8_t,
-    pub vifr_port_opackets: i64,
-    pub __isset_vifr_port_opackets: u_int8_t,
-    pub vifr_port_oerrors: i64,
-    pub __isset_vifr_port_oerrors: u_int8_t,
-    pub vifr_port_osyscalls: i64,
-    pub __isset_vifr_port_osyscalls: u_int8_t,
-    pub vifr_dev_ibytes: i64,
-    pub __isset_vifr_dev_ibytes: u_int8_t,
-    pub vifr_dev_ipackets: i64,
-    pub __isset_vifr_dev_ipackets: u_int8_t,
-    pub vifr_dev_ierrors: i64,
-    pub __isset_vifr_dev_ierrors: u_int8_t,
-    pub vifr_dev_inombufs: i64,
-    pub __isset_vifr_dev_inombufs: u_int8_t,
-    pub vifr_dev_obytes: i64,
-    pub __isset_vifr_dev_obytes: u_int8_t,
-    pub vifr_dev_opackets: i64,
-    pub __isset_vifr_dev_opackets: u_int8_t,
-    pub vifr_dev_oerrors: i64,
-    pub __isset_vifr_dev_oerrors: u_int8_t,
-    pub vifr_ref_cnt: i32,
-    pub __isset_vifr_ref_cnt: u_int8_t,
-    pub vifr_marker: i32,
-    pub __isset_vifr_marker: u_int8_t,
-    pub vifr_mac: *mut i8,
-    pub vifr_mac_size: u_int32_t,
-    pub __isset_vifr_mac: u_int8_t,
-    pub vifr_ip: u32,
-    pub __isset_vifr_ip: u_int8_t,
-    pub vifr_ip6_u: u64,
-    pub __isset_vifr_ip6_u: u_int8_t,
-    pub vifr_ip6_l: u64,
-    pub __isset_vifr_ip6_l: u_int8_t,
-    pub vifr_context: i32,
-    pub __isset_vifr_context: u_int8_t,
-    pub vifr_mir_id: i16,
-    pub __isset_vifr_mir_id: u_int8_t,
-    pub vifr_speed: i32,
-    pub __isset_vifr_speed: u_int8_t,
-    pub vifr_duplex: i32,
-    pub __isset_vifr_duplex: u_int8_t,
-    pub vifr_vlan_id: i16,
-    pub __isset_vifr_vlan_id: u_int8_t,
-    pub vifr_parent_vif_idx: i32,
-    pub __isset_vifr_parent_vif_idx: u_int8_t,
-    pub vifr_nh_id: i32,
-    pub __isset_vifr_nh_id: u_int8_t,
-    pub vifr_cross_connect_idx: i32,
-    pub __isset_vifr_cross_connect_idx: u_int8_t,
-    pub vifr_src_mac: *mut i8,
-    pub vifr_src_mac_size: u_int32_t,
-    pub __isset_vifr_src_mac: u_int8_t,
-    pub vifr_bridge_idx: *mut i32,
-    pub vifr_bridge_idx_size: u_int32_t,
-    pub __isset_vifr_bridge_idx: u_int8_t,
-    pub vifr_ovlan_id: i16,
-    pub __isset_vifr_ovlan_id: u_int8_t,
-    pub vifr_transport: i8,
-    pub __isset_vifr_transport: u_int8_t,
-    pub vifr_fat_flow_protocol_port: *mut i32,
-    pub vifr_fat_flow_protocol_port_size: u_int32_t,
-    pub __isset_vifr_fat_flow_protocol_port: u_int8_t,
-    pub vifr_qos_map_index: i16,
-    pub __isset_vifr_qos_map_index: u_int8_t,
-    pub vifr_in_mirror_md: *mut i8,
-    pub vifr_in_mirror_md_size: u_int32_t,
-    pub __isset_vifr_in_mirror_md: u_int8_t,
-    pub vifr_out_mirror_md: *mut i8,
-    pub vifr_out_mirror_md_size: u_int32_t,
-    pub __isset_vifr_out_mirror_md: u_int8_t,
-    pub vifr_dpackets: u64,
-    pub __isset_vifr_dpackets: u_int8_t,
-    pub vifr_hw_queues: *mut i16,
-    pub vifr_hw_queues_size: u_int32_t,
-    pub __isset_vifr_hw_queues: u_int8_t,
-    pub vifr_isid: u32,
-    pub __isset_vifr_isid: u_int8_t,
-    pub vifr_pbb_mac: *mut i8,
-    pub vifr_pbb_mac_size: u_int32_t,
-    pub __isset_vifr_pbb_mac: u_int8_t,
-    pub vifr_vhostuser_mode: i8,
-    pub __isset_vifr_vhostuser_mode: u_int8_t,
-    pub vifr_mcast_vrf: i32,
-    pub __isset_vifr_mcast_vrf: u_int8_t,
-    pub vifr_if_guid: *mut i8,
-    pub vifr_if_guid_size: u_int32_t,
-    pub __isset_vifr_if_guid: u_int8_t,
-    pub vifr_fat_flow_exclude_ip_list: *mut u64,
-    pub vifr_fat_flow_exclude_ip_list_size: u_int32_t,
-    pub __isset_vifr_fat_flow_exclude_ip_list: u_int8_t,
-    pub vifr_fat_flow_exclude_ip6_u_list: *mut u64,
-    pub vifr_fat_flow_exclude_ip6_u_list_size: u_int32_t,
-    pub __isset_vifr_fat_flow_exclude_ip6_u_list: u_int8_t,
-    pub vifr_fat_flow_exclude_ip6_l_list: *mut u64,
-    pub vifr_fat_flow_exclude_ip6_l_list_size: u_int32_t,
-    pub __isset_vifr_fat_flow_exclude_ip6_l_list: u_int8_t,
-    pub vifr_fat_flow_exclude_ip6_plen_list: *mut u16,
-    pub vifr_fat_flow_exclude_ip6_plen_list_size: u_int32_t,
-    pub __isset_vifr_fat_flow_exclude_ip6_plen_list: u_int8_t,
-    pub vifr_fat_flow_src_prefix_h: *mut u64,
-    pub vifr_fat_flow_src_prefix_h_size: u_int32_t,
-    pub __isset_vifr_fat_flow_src_prefix_h: u_int8_t,
-    pub vifr_fat_flow_src_prefix_l: *mut u64,
-    pub vifr_fat_flow_src_prefix_l_size: u_int32_t,
-    pub __isset_vifr_fat_flow_src_prefix_l: u_int8_t,
-    pub vifr_fat_flow_src_prefix_mask: *mut i8,
-    pub vifr_fat_flow_src_prefix_mask_size: u_int32_t,
-    pub __isset_vifr_fat_flow_src_prefix_mask: u_int8_t,
-    pub vifr_fat_flow_src_aggregate_plen: *mut i8,
-    pub vifr_fat_flow_src_aggregate_plen_size: u_int32_t,
-    pub __isset_vifr_fat_flow_src_aggregate_plen: u_int8_t,
-    pub vifr_fat_flow_dst_prefix_h: *mut u64,
-    pub vifr_fat_flow_dst_prefix_h_size: u_int32_t,
-    pub __isset_vifr_fat_flow_dst_prefix_h: u_int8_t,
-    pub vifr_fat_flow_dst_prefix_l: *mut u64,
-    pub vifr_fat_flow_dst_prefix_l_size: u_int32_t,
-    pub __isset_vifr_fat_flow_dst_prefix_l: u_int8_t,
-    pub vifr_fat_flow_dst_prefix_mask: *mut i8,
-    pub vifr_fat_flow_dst_prefix_mask_size: u_int32_t,
-    pub __isset_vifr_fat_flow_dst_prefix_mask: u_int8_t,
-    pub vifr_fat_flow_dst_aggregate_plen: *mut i8,
-    pub vifr_fat_flow_dst_aggregate_plen_size: u_int32_t,
-    pub __isset_vifr_fat_flow_dst_aggregate_plen: u_int8_t,
-    pub vifr_intf_status: i8,
-    pub __isset_vifr_intf_status: u_int8_t,
-    pub vifr_fab_name: *mut i8,
-    pub vifr_fab_name_size: u_int32_t,
-    pub __isset_vifr_fab_name: u_int8_t,
-    pub vifr_fab_drv_name: *mut i8,
-    pub vifr_fab_drv_name_size: u_int32_t,
-    pub __isset_vifr_fab_drv_name: u_int8_t,
-    pub vifr_num_bond_slave: i8,
-    pub __isset_vifr_num_bond_slave: u_int8_t,
-    pub vifr_bond_slave_name: *mut i8,
-    pub vifr_bond_slave_name_size: u_int32_t,
-    pub __isset_vifr_bond_slave_name: u_int8_t,
-    pub vifr_bond_slave_drv_name: *mut i8,
-    pub vifr_bond_slave_drv_name_size: u_int32_t,
-    pub __isset_vifr_bond_slave_drv_name: u_int8_t,
-    pub vifr_vlan_tag: u32,
-    pub __isset_vifr_vlan_tag: u_int8_t,
-    pub vifr_vlan_name: *mut i8,
-    pub vifr_vlan_name_size: u_int32_t,
-    pub __isset_vifr_vlan_name: u_int8_t,
-}
-pub type vr_interface_req = _vr_interface_req;
-extern "C" {
-    pub fn vr_interface_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_interface_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_interface_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_interface_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_interface_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_interface_req_process(pvr_interface_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_vxlan_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vxlanr_rid: i16,
-    pub __isset_vxlanr_rid: u_int8_t,
-    pub vxlanr_vnid: i32,
-    pub __isset_vxlanr_vnid: u_int8_t,
-    pub vxlanr_nhid: i32,
-    pub __isset_vxlanr_nhid: u_int8_t,
-}
-pub type vr_vxlan_req = _vr_vxlan_req;
-extern "C" {
-    pub fn vr_vxlan_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vxlan_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vxlan_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vxlan_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vxlan_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_vxlan_req_process(pvr_vxlan_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_route_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub rtr_vrf_id: i32,
-    pub __isset_rtr_vrf_id: u_int8_t,
-    pub rtr_family: i32,
-    pub __isset_rtr_family: u_int8_t,
-    pub rtr_prefix: *mut i8,
-    pub rtr_prefix_size: u_int32_t,
-    pub __isset_rtr_prefix: u_int8_t,
-    pub rtr_prefix_len: i32,
-    pub __isset_rtr_prefix_len: u_int8_t,
-    pub rtr_rid: i16,
-    pub __isset_rtr_rid: u_int8_t,
-    pub rtr_label_flags: i16,
-    pub __isset_rtr_label_flags: u_int8_t,
-    pub rtr_label: i32,
-    pub __isset_rtr_label: u_int8_t,
-    pub rtr_nh_id: i32,
-    pub __isset_rtr_nh_id: u_int8_t,
-    pub rtr_marker: *mut i8,
-    pub rtr_marker_size: u_int32_t,
-    pub __isset_rtr_marker: u_int8_t,
-    pub rtr_marker_plen: i32,
-    pub __isset_rtr_marker_plen: u_int8_t,
-    pub rtr_mac: *mut i8,
-    pub rtr_mac_size: u_int32_t,
-    pub __isset_rtr_mac: u_int8_t,
-    pub rtr_replace_plen: i32,
-    pub __isset_rtr_replace_plen: u_int8_t,
-    pub rtr_index: i32,
-    pub __isset_rtr_index: u_int8_t,
-}
-pub type vr_route_req = _vr_route_req;
-extern "C" {
-    pub fn vr_route_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_route_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_route_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_route_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_route_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_route_req_process(pvr_route_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_mpls_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub mr_label: i32,
-    pub __isset_mr_label: u_int8_t,
-    pub mr_rid: i16,
-    pub __isset_mr_rid: u_int8_t,
-    pub mr_nhid: i32,
-    pub __isset_mr_nhid: u_int8_t,
-    pub mr_marker: i32,
-    pub __isset_mr_marker: u_int8_t,
-}
-pub type vr_mpls_req = _vr_mpls_req;
-extern "C" {
-    pub fn vr_mpls_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mpls_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mpls_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mpls_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mpls_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_mpls_req_process(pvr_mpls_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_mirror_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub mirr_index: i16,
-    pub __isset_mirr_index: u_int8_t,
-    pub mirr_rid: i16,
-    pub __isset_mirr_rid: u_int8_t,
-    pub mirr_nhid: i32,
-    pub __isset_mirr_nhid: u_int8_t,
-    pub mirr_users: i32,
-    pub __isset_mirr_users: u_int8_t,
-    pub mirr_flags: i32,
-    pub __isset_mirr_flags: u_int8_t,
-    pub mirr_marker: i32,
-    pub __isset_mirr_marker: u_int8_t,
-    pub mirr_vni: i32,
-    pub __isset_mirr_vni: u_int8_t,
-    pub mirr_vlan: i16,
-    pub __isset_mirr_vlan: u_int8_t,
-}
-pub type vr_mirror_req = _vr_mirror_req;
-extern "C" {
-    pub fn vr_mirror_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mirror_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mirror_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mirror_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mirror_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_mirror_req_process(pvr_mirror_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_vrf_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vrf_rid: i16,
-    pub __isset_vrf_rid: u_int8_t,
-    pub vrf_idx: i32,
-    pub __isset_vrf_idx: u_int8_t,
-    pub vrf_flags: i32,
-    pub __isset_vrf_flags: u_int8_t,
-    pub vrf_hbfl_vif_idx: i32,
-    pub __isset_vrf_hbfl_vif_idx: u_int8_t,
-    pub vrf_hbfr_vif_idx: i32,
-    pub __isset_vrf_hbfr_vif_idx: u_int8_t,
-    pub vrf_marker: i32,
-    pub __isset_vrf_marker: u_int8_t,
-}
-pub type vr_vrf_req = _vr_vrf_req;
-extern "C" {
-    pub fn vr_vrf_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_vrf_req_process(pvr_vrf_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_flow_req {
-    pub fr_op: flow_op,
-    pub __isset_fr_op: u_int8_t,
-    pub fr_rid: i16,
-    pub __isset_fr_rid: u_int8_t,
-    pub fr_index: i32,
-    pub __isset_fr_index: u_int8_t,
-    pub fr_action: i16,
-    pub __isset_fr_action: u_int8_t,
-    pub fr_flags: i16,
-    pub __isset_fr_flags: u_int8_t,
-    pub fr_rindex: i32,
-    pub __isset_fr_rindex: u_int8_t,
-    pub fr_family: i32,
-    pub __isset_fr_family: u_int8_t,
-    pub fr_flow_sip_u: u64,
-    pub __isset_fr_flow_sip_u: u_int8_t,
-    pub fr_flow_sip_l: u64,
-    pub __isset_fr_flow_sip_l: u_int8_t,
-    pub fr_flow_dip_u: u64,
-    pub __isset_fr_flow_dip_u: u_int8_t,
-    pub fr_flow_dip_l: u64,
-    pub __isset_fr_flow_dip_l: u_int8_t,
-    pub fr_flow_sport: u16,
-    pub __isset_fr_flow_sport: u_int8_t,
-    pub fr_flow_dport: u16,
-    pub __isset_fr_flow_dport: u_int8_t,
-    pub fr_flow_proto: i8,
-    pub __isset_fr_flow_proto: u_int8_t,
-    pub fr_flow_vrf: u16,
-    pub __isset_fr_flow_vrf: u_int8_t,
-    pub fr_flow_dvrf: u16,
-    pub __isset_fr_flow_dvrf: u_int8_t,
-    pub fr_mir_id: u16,
-    pub __isset_fr_mir_id: u_int8_t,
-    pub fr_sec_mir_id: u16,
-    pub __isset_fr_sec_mir_id: u_int8_t,
-    pub fr_mir_sip: u32,
-    pub __isset_fr_mir_sip: u_int8_t,
-    pub fr_mir_sport: u16,
-    pub __isset_fr_mir_sport: u_int8_t,
-    pub fr_pcap_meta_data: *mut i8,
-    pub fr_pcap_meta_data_size: u_int32_t,
-    pub __isset_fr_pcap_meta_data: u_int8_t,
-    pub fr_mir_vrf: u16,
-    pub __isset_fr_mir_vrf: u_int8_t,
-    pub fr_ecmp_nh_index: u32,
-    pub __isset_fr_ecmp_nh_index: u_int8_t,
-    pub fr_src_nh_index: u32,
-    pub __isset_fr_src_nh_index: u_int8_t,
-    pub fr_flow_nh_id: u32,
-    pub __isset_fr_flow_nh_id: u_int8_t,
-    pub fr_drop_reason: u16,
-    pub __isset_fr_drop_reason: u_int8_t,
-    pub fr_gen_id: i8,
-    pub __isset_fr_gen_id: u_int8_t,
-    pub fr_rflow_sip_u: u64,
-    pub __isset_fr_rflow_sip_u: u_int8_t,
-    pub fr_rflow_sip_l: u64,
-    pub __isset_fr_rflow_sip_l: u_int8_t,
-    pub fr_rflow_dip_u: u64,
-    pub __isset_fr_rflow_dip_u: u_int8_t,
-    pub fr_rflow_dip_l: u64,
-    pub __isset_fr_rflow_dip_l: u_int8_t,
-    pub fr_rflow_nh_id: u32,
-    pub __isset_fr_rflow_nh_id: u_int8_t,
-    pub fr_rflow_sport: u16,
-    pub __isset_fr_rflow_sport: u_int8_t,
-    pub fr_rflow_dport: u16,
-    pub __isset_fr_rflow_dport: u_int8_t,
-    pub fr_qos_id: u16,
-    pub __isset_fr_qos_id: u_int8_t,
-    pub fr_ttl: i8,
-    pub __isset_fr_ttl: u_int8_t,
-    pub fr_extflags: i16,
-    pub __isset_fr_extflags: u_int8_t,
-    pub fr_flags1: i16,
-    pub __isset_fr_flags1: u_int8_t,
-}
-pub type vr_flow_req = _vr_flow_req;
-extern "C" {
-    pub fn vr_flow_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_flow_req_process(pvr_flow_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_vrf_assign_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub var_rid: i16,
-    pub __isset_var_rid: u_int8_t,
-    pub var_vif_index: i16,
-    pub __isset_var_vif_index: u_int8_t,
-    pub var_vif_vrf: i32,
-    pub __isset_var_vif_vrf: u_int8_t,
-    pub var_vlan_id: i16,
-    pub __isset_var_vlan_id: u_int8_t,
-    pub var_marker: i16,
-    pub __isset_var_marker: u_int8_t,
-    pub var_nh_id: i32,
-    pub __isset_var_nh_id: u_int8_t,
-}
-pub type vr_vrf_assign_req = _vr_vrf_assign_req;
-extern "C" {
-    pub fn vr_vrf_assign_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_assign_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_assign_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_assign_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_assign_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_vrf_assign_req_process(pvr_vrf_assign_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_vrf_stats_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vsr_rid: i16,
-    pub __isset_vsr_rid: u_int8_t,
-    pub vsr_family: i16,
-    pub __isset_vsr_family: u_int8_t,
-    pub vsr_type: i16,
-    pub __isset_vsr_type: u_int8_t,
-    pub vsr_vrf: i32,
-    pub __isset_vsr_vrf: u_int8_t,
-    pub vsr_discards: i64,
-    pub __isset_vsr_discards: u_int8_t,
-    pub vsr_resolves: i64,
-    pub __isset_vsr_resolves: u_int8_t,
-    pub vsr_receives: i64,
-    pub __isset_vsr_receives: u_int8_t,
-    pub vsr_ecmp_composites: i64,
-    pub __isset_vsr_ecmp_composites: u_int8_t,
-    pub vsr_l2_mcast_composites: i64,
-    pub __isset_vsr_l2_mcast_composites: u_int8_t,
-    pub vsr_fabric_composites: i64,
-    pub __isset_vsr_fabric_composites: u_int8_t,
-    pub vsr_udp_tunnels: i64,
-    pub __isset_vsr_udp_tunnels: u_int8_t,
-    pub vsr_udp_mpls_tunnels: i64,
-    pub __isset_vsr_udp_mpls_tunnels: u_int8_t,
-    pub vsr_gre_mpls_tunnels: i64,
-    pub __isset_vsr_gre_mpls_tunnels: u_int8_t,
-    pub vsr_l2_encaps: i64,
-    pub __isset_vsr_l2_encaps: u_int8_t,
-    pub vsr_encaps: i64,
-    pub __isset_vsr_encaps: u_int8_t,
-    pub vsr_marker: i16,
-    pub __isset_vsr_marker: u_int8_t,
-    pub vsr_gros: i64,
-    pub __isset_vsr_gros: u_int8_t,
-    pub vsr_diags: i64,
-    pub __isset_vsr_diags: u_int8_t,
-    pub vsr_encap_composites: i64,
-    pub __isset_vsr_encap_composites: u_int8_t,
-    pub vsr_evpn_composites: i64,
-    pub __isset_vsr_evpn_composites: u_int8_t,
-    pub vsr_vrf_translates: i64,
-    pub __isset_vsr_vrf_translates: u_int8_t,
-    pub vsr_vxlan_tunnels: i64,
-    pub __isset_vsr_vxlan_tunnels: u_int8_t,
-    pub vsr_arp_virtual_proxy: i64,
-    pub __isset_vsr_arp_virtual_proxy: u_int8_t,
-    pub vsr_arp_virtual_stitch: i64,
-    pub __isset_vsr_arp_virtual_stitch: u_int8_t,
-    pub vsr_arp_virtual_flood: i64,
-    pub __isset_vsr_arp_virtual_flood: u_int8_t,
-    pub vsr_arp_physical_stitch: i64,
-    pub __isset_vsr_arp_physical_stitch: u_int8_t,
-    pub vsr_arp_tor_proxy: i64,
-    pub __isset_vsr_arp_tor_proxy: u_int8_t,
-    pub vsr_arp_physical_flood: i64,
-    pub __isset_vsr_arp_physical_flood: u_int8_t,
-    pub vsr_l2_receives: i64,
-    pub __isset_vsr_l2_receives: u_int8_t,
-    pub vsr_uuc_floods: i64,
-    pub __isset_vsr_uuc_floods: u_int8_t,
-    pub vsr_pbb_tunnels: i64,
-    pub __isset_vsr_pbb_tunnels: u_int8_t,
-    pub vsr_udp_mpls_over_mpls_tunnels: i64,
-    pub __isset_vsr_udp_mpls_over_mpls_tunnels: u_int8_t,
-}
-pub type vr_vrf_stats_req = _vr_vrf_stats_req;
-extern "C" {
-    pub fn vr_vrf_stats_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_stats_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_stats_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_stats_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_vrf_stats_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_vrf_stats_req_process(pvr_vrf_stats_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_response {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub resp_code: i32,
-    pub __isset_resp_code: u_int8_t,
-}
-pub type vr_response = _vr_response;
-extern "C" {
-    pub fn vr_response_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_response_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_response_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_response_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_response_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_response_process(pvr_response: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vrouter_ops {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vo_rid: i32,
-    pub __isset_vo_rid: u_int8_t,
-    pub vo_mpls_labels: i32,
-    pub __isset_vo_mpls_labels: u_int8_t,
-    pub vo_nexthops: i32,
-    pub __isset_vo_nexthops: u_int8_t,
-    pub vo_bridge_entries: i32,
-    pub __isset_vo_bridge_entries: u_int8_t,
-    pub vo_oflow_bridge_entries: i32,
-    pub __isset_vo_oflow_bridge_entries: u_int8_t,
-    pub vo_flow_entries: i32,
-    pub __isset_vo_flow_entries: u_int8_t,
-    pub vo_oflow_entries: i32,
-    pub __isset_vo_oflow_entries: u_int8_t,
-    pub vo_interfaces: i32,
-    pub __isset_vo_interfaces: u_int8_t,
-    pub vo_mirror_entries: i32,
-    pub __isset_vo_mirror_entries: u_int8_t,
-    pub vo_vrfs: i32,
-    pub __isset_vo_vrfs: u_int8_t,
-    pub vo_build_info: *mut ::std::os::raw::c_char,
-    pub __isset_vo_build_info: u_int8_t,
-    pub vo_log_level: u32,
-    pub __isset_vo_log_level: u_int8_t,
-    pub vo_log_type_enable: *mut i32,
-    pub vo_log_type_enable_size: u_int32_t,
-    pub __isset_vo_log_type_enable: u_int8_t,
-    pub vo_log_type_disable: *mut i32,
-    pub vo_log_type_disable_size: u_int32_t,
-    pub __isset_vo_log_type_disable: u_int8_t,
-    pub vo_perfr: i32,
-    pub __isset_vo_perfr: u_int8_t,
-    pub vo_perfs: i32,
-    pub __isset_vo_perfs: u_int8_t,
-    pub vo_from_vm_mss_adj: i32,
-    pub __isset_vo_from_vm_mss_adj: u_int8_t,
-    pub vo_to_vm_mss_adj: i32,
-    pub __isset_vo_to_vm_mss_adj: u_int8_t,
-    pub vo_perfr1: i32,
-    pub __isset_vo_perfr1: u_int8_t,
-    pub vo_perfr2: i32,
-    pub __isset_vo_perfr2: u_int8_t,
-    pub vo_perfr3: i32,
-    pub __isset_vo_perfr3: u_int8_t,
-    pub vo_perfp: i32,
-    pub __isset_vo_perfp: u_int8_t,
-    pub vo_perfq1: i32,
-    pub __isset_vo_perfq1: u_int8_t,
-    pub vo_perfq2: i32,
-    pub __isset_vo_perfq2: u_int8_t,
-    pub vo_perfq3: i32,
-    pub __isset_vo_perfq3: u_int8_t,
-    pub vo_udp_coff: i32,
-    pub __isset_vo_udp_coff: u_int8_t,
-    pub vo_flow_hold_limit: i32,
-    pub __isset_vo_flow_hold_limit: u_int8_t,
-    pub vo_mudp: i32,
-    pub __isset_vo_mudp: u_int8_t,
-    pub vo_flow_used_entries: u32,
-    pub __isset_vo_flow_used_entries: u_int8_t,
-    pub vo_flow_used_oentries: u32,
-    pub __isset_vo_flow_used_oentries: u_int8_t,
-    pub vo_bridge_used_entries: u32,
-    pub __isset_vo_bridge_used_entries: u_int8_t,
-    pub vo_bridge_used_oentries: u32,
-    pub __isset_vo_bridge_used_oentries: u_int8_t,
-    pub vo_burst_tokens: i32,
-    pub __isset_vo_burst_tokens: u_int8_t,
-    pub vo_burst_interval: i32,
-    pub __isset_vo_burst_interval: u_int8_t,
-    pub vo_burst_step: i32,
-    pub __isset_vo_burst_step: u_int8_t,
-    pub vo_memory_alloc_checks: i32,
-    pub __isset_vo_memory_alloc_checks: u_int8_t,
-    pub vo_priority_tagging: u32,
-    pub __isset_vo_priority_tagging: u_int8_t,
-    pub vo_vif_bridge_entries: i32,
-    pub __isset_vo_vif_bridge_entries: u_int8_t,
-    pub vo_vif_oflow_bridge_entries: i32,
-    pub __isset_vo_vif_oflow_bridge_entries: u_int8_t,
-    pub vo_packet_dump: i32,
-    pub __isset_vo_packet_dump: u_int8_t,
-    pub vo_pkt_droplog_bufsz: i32,
-    pub __isset_vo_pkt_droplog_bufsz: u_int8_t,
-    pub vo_pkt_droplog_buf_en: i8,
-    pub __isset_vo_pkt_droplog_buf_en: u_int8_t,
-    pub vo_pkt_droplog_en: i8,
-    pub __isset_vo_pkt_droplog_en: u_int8_t,
-    pub vo_pkt_droplog_min_en: i8,
-    pub __isset_vo_pkt_droplog_min_en: u_int8_t,
-    pub vo_close_flow_on_tcp_rst: i8,
-    pub __isset_vo_close_flow_on_tcp_rst: u_int8_t,
-}
-pub type vrouter_ops = _vrouter_ops;
-extern "C" {
-    pub fn vrouter_ops_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vrouter_ops_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vrouter_ops_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vrouter_ops_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vrouter_ops_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vrouter_ops_process(pvrouter_ops: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_mem_stats_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vms_rid: i16,
-    pub __isset_vms_rid: u_int8_t,
-    pub vms_alloced: i64,
-    pub __isset_vms_alloced: u_int8_t,
-    pub vms_freed: i64,
-    pub __isset_vms_freed: u_int8_t,
-    pub vms_assembler_table_object: i64,
-    pub __isset_vms_assembler_table_object: u_int8_t,
-    pub vms_bridge_mac_object: i64,
-    pub __isset_vms_bridge_mac_object: u_int8_t,
-    pub vms_btable_object: i64,
-    pub __isset_vms_btable_object: u_int8_t,
-    pub vms_build_info_object: i64,
-    pub __isset_vms_build_info_object: u_int8_t,
-    pub vms_defer_object: i64,
-    pub __isset_vms_defer_object: u_int8_t,
-    pub vms_drop_stats_object: i64,
-    pub __isset_vms_drop_stats_object: u_int8_t,
-    pub vms_drop_stats_req_object: i64,
-    pub __isset_vms_drop_stats_req_object: u_int8_t,
-    pub vms_flow_queue_object: i64,
-    pub __isset_vms_flow_queue_object: u_int8_t,
-    pub vms_flow_req_object: i64,
-    pub __isset_vms_flow_req_object: u_int8_t,
-    pub vms_flow_req_path_object: i64,
-    pub __isset_vms_flow_req_path_object: u_int8_t,
-    pub vms_flow_hold_stat_object: i64,
-    pub __isset_vms_flow_hold_stat_object: u_int8_t,
-    pub vms_flow_link_local_object: i64,
-    pub __isset_vms_flow_link_local_object: u_int8_t,
-    pub vms_flow_metadata_object: i64,
-    pub __isset_vms_flow_metadata_object: u_int8_t,
-    pub vms_flow_table_data_object: i64,
-    pub __isset_vms_flow_table_data_object: u_int8_t,
-    pub vms_flow_table_info_object: i64,
-    pub __isset_vms_flow_table_info_object: u_int8_t,
-    pub vms_fragment_object: i64,
-    pub __isset_vms_fragment_object: u_int8_t,
-    pub vms_fragment_queue_object: i64,
-    pub __isset_vms_fragment_queue_object: u_int8_t,
-    pub vms_fragment_queue_element_object: i64,
-    pub __isset_vms_fragment_queue_element_object: u_int8_t,
-    pub vms_fragment_scanner_object: i64,
-    pub __isset_vms_fragment_scanner_object: u_int8_t,
-    pub vms_hpacket_pool_object: i64,
-    pub __isset_vms_hpacket_pool_object: u_int8_t,
-    pub vms_htable_object: i64,
-    pub __isset_vms_htable_object: u_int8_t,
-    pub vms_interface_object: i64,
-    pub __isset_vms_interface_object: u_int8_t,
-    pub vms_interface_mac_object: i64,
-    pub __isset_vms_interface_mac_object: u_int8_t,
-    pub vms_interface_req_object: i64,
-    pub __isset_vms_interface_req_object: u_int8_t,
-    pub vms_interface_req_mac_object: i64,
-    pub __isset_vms_interface_req_mac_object: u_int8_t,
-    pub vms_interface_req_name_object: i64,
-    pub __isset_vms_interface_req_name_object: u_int8_t,
-    pub vms_interface_stats_object: i64,
-    pub __isset_vms_interface_stats_object: u_int8_t,
-    pub vms_interface_table_object: i64,
-    pub __isset_vms_interface_table_object: u_int8_t,
-    pub vms_interface_vrf_table_object: i64,
-    pub __isset_vms_interface_vrf_table_object: u_int8_t,
-    pub vms_itable_object: i64,
-    pub __isset_vms_itable_object: u_int8_t,
-    pub vms_malloc_object: i64,
-    pub __isset_vms_malloc_object: u_int8_t,
-    pub vms_message_object: i64,
-    pub __isset_vms_message_object: u_int8_t,
-    pub vms_message_response_object: i64,
-    pub __isset_vms_message_response_object: u_int8_t,
-    pub vms_message_dump_object: i64,
-    pub __isset_vms_message_dump_object: u_int8_t,
-    pub vms_mem_stats_req_object: i64,
-    pub __isset_vms_mem_stats_req_object: u_int8_t,
-    pub vms_mirror_object: i64,
-    pub __isset_vms_mirror_object: u_int8_t,
-    pub vms_mirror_table_object: i64,
-    pub __isset_vms_mirror_table_object: u_int8_t,
-    pub vms_mirror_meta_object: i64,
-    pub __isset_vms_mirror_meta_object: u_int8_t,
-    pub vms_mtrie_object: i64,
-    pub __isset_vms_mtrie_object: u_int8_t,
-    pub vms_mtrie_bucket_object: i64,
-    pub __isset_vms_mtrie_bucket_object: u_int8_t,
-    pub vms_mtrie_stats_object: i64,
-    pub __isset_vms_mtrie_stats_object: u_int8_t,
-    pub vms_mtrie_table_object: i64,
-    pub __isset_vms_mtrie_table_object: u_int8_t,
-    pub vms_network_address_object: i64,
-    pub __isset_vms_network_address_object: u_int8_t,
-    pub vms_nexthop_object: i64,
-    pub __isset_vms_nexthop_object: u_int8_t,
-    pub vms_nexthop_component_object: i64,
-    pub __isset_vms_nexthop_component_object: u_int8_t,
-    pub vms_nexthop_req_list_object: i64,
-    pub __isset_vms_nexthop_req_list_object: u_int8_t,
-    pub vms_nexthop_req_encap_object: i64,
-    pub __isset_vms_nexthop_req_encap_object: u_int8_t,
-    pub vms_nexthop_req_object: i64,
-    pub __isset_vms_nexthop_req_object: u_int8_t,
-    pub vms_route_table_object: i64,
-    pub __isset_vms_route_table_object: u_int8_t,
-    pub vms_route_req_mac_object: i64,
-    pub __isset_vms_route_req_mac_object: u_int8_t,
-    pub vms_timer_object: i64,
-    pub __isset_vms_timer_object: u_int8_t,
-    pub vms_usock_object: i64,
-    pub __isset_vms_usock_object: u_int8_t,
-    pub vms_usock_poll_object: i64,
-    pub __isset_vms_usock_poll_object: u_int8_t,
-    pub vms_usock_buf_object: i64,
-    pub __isset_vms_usock_buf_object: u_int8_t,
-    pub vms_usock_iovec_object: i64,
-    pub __isset_vms_usock_iovec_object: u_int8_t,
-    pub vms_vrouter_req_object: i64,
-    pub __isset_vms_vrouter_req_object: u_int8_t,
-    pub vms_interface_fat_flow_config_object: i64,
-    pub __isset_vms_interface_fat_flow_config_object: u_int8_t,
-    pub vms_qos_map_object: i64,
-    pub __isset_vms_qos_map_object: u_int8_t,
-    pub vms_fc_object: i64,
-    pub __isset_vms_fc_object: u_int8_t,
-    pub vms_interface_mirror_meta_object: i64,
-    pub __isset_vms_interface_mirror_meta_object: u_int8_t,
-    pub vms_interface_req_mirror_meta_object: i64,
-    pub __isset_vms_interface_req_mirror_meta_object: u_int8_t,
-    pub vms_interface_bridge_lock_object: i64,
-    pub __isset_vms_interface_bridge_lock_object: u_int8_t,
-    pub vms_interface_queue_object: i64,
-    pub __isset_vms_interface_queue_object: u_int8_t,
-    pub vms_interface_req_pbb_mac_object: i64,
-    pub __isset_vms_interface_req_pbb_mac_object: u_int8_t,
-    pub vms_nexthop_req_bmac_object: i64,
-    pub __isset_vms_nexthop_req_bmac_object: u_int8_t,
-    pub vms_interface_req_bridge_id_object: i64,
-    pub __isset_vms_interface_req_bridge_id_object: u_int8_t,
-    pub vms_interface_fat_flow_ipv4_exclude_list_object: i64,
-    pub __isset_vms_interface_fat_flow_ipv4_exclude_list_object: u_int8_t,
-    pub vms_interface_fat_flow_ipv6_exclude_list_object: i64,
-    pub __isset_vms_interface_fat_flow_ipv6_exclude_list_object: u_int8_t,
-}
-pub type vr_mem_stats_req = _vr_mem_stats_req;
-extern "C" {
-    pub fn vr_mem_stats_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mem_stats_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mem_stats_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mem_stats_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_mem_stats_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_mem_stats_req_process(pvr_mem_stats_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_info_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vdu_rid: i16,
-    pub __isset_vdu_rid: u_int8_t,
-    pub vdu_index: i16,
-    pub __isset_vdu_index: u_int8_t,
-    pub vdu_buff_table_id: i16,
-    pub __isset_vdu_buff_table_id: u_int8_t,
-    pub vdu_marker: i16,
-    pub __isset_vdu_marker: u_int8_t,
-    pub vdu_msginfo: i16,
-    pub __isset_vdu_msginfo: u_int8_t,
-    pub vdu_outbufsz: i32,
-    pub __isset_vdu_outbufsz: u_int8_t,
-    pub vdu_inbuf: *mut i8,
-    pub vdu_inbuf_size: u_int32_t,
-    pub __isset_vdu_inbuf: u_int8_t,
-    pub vdu_proc_info: *mut i8,
-    pub vdu_proc_info_size: u_int32_t,
-    pub __isset_vdu_proc_info: u_int8_t,
-}
-pub type vr_info_req = _vr_info_req;
-extern "C" {
-    pub fn vr_info_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_info_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_info_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_info_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_info_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_info_req_process(pvr_info_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_pkt_drop_log_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vdl_rid: i16,
-    pub __isset_vdl_rid: u_int8_t,
-    pub vdl_core: i16,
-    pub __isset_vdl_core: u_int8_t,
-    pub vdl_log_idx: i16,
-    pub __isset_vdl_log_idx: u_int8_t,
-    pub vdl_max_num_cores: i16,
-    pub __isset_vdl_max_num_cores: u_int8_t,
-    pub vdl_pkt_droplog_max_bufsz: i16,
-    pub __isset_vdl_pkt_droplog_max_bufsz: u_int8_t,
-    pub vdl_pkt_droplog_en: i16,
-    pub __isset_vdl_pkt_droplog_en: u_int8_t,
-    pub vdl_pkt_droplog_sysctl_en: i16,
-    pub __isset_vdl_pkt_droplog_sysctl_en: u_int8_t,
-    pub vdl_pkt_droplog_arr: *mut i8,
-    pub vdl_pkt_droplog_arr_size: u_int32_t,
-    pub __isset_vdl_pkt_droplog_arr: u_int8_t,
-}
-pub type vr_pkt_drop_log_req = _vr_pkt_drop_log_req;
-extern "C" {
-    pub fn vr_pkt_drop_log_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_pkt_drop_log_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_pkt_drop_log_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_pkt_drop_log_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_pkt_drop_log_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_pkt_drop_log_req_process(pvr_pkt_drop_log_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_drop_stats_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub vds_rid: i16,
-    pub __isset_vds_rid: u_int8_t,
-    pub vds_core: i16,
-    pub __isset_vds_core: u_int8_t,
-    pub vds_discard: i64,
-    pub __isset_vds_discard: u_int8_t,
-    pub vds_pcpu_stats_failure_status: i8,
-    pub __isset_vds_pcpu_stats_failure_status: u_int8_t,
-    pub vds_pull: i64,
-    pub __isset_vds_pull: u_int8_t,
-    pub vds_invalid_if: i64,
-    pub __isset_vds_invalid_if: u_int8_t,
-    pub vds_invalid_arp: i64,
-    pub __isset_vds_invalid_arp: u_int8_t,
-    pub vds_trap_no_if: i64,
-    pub __isset_vds_trap_no_if: u_int8_t,
-    pub vds_nowhere_to_go: i64,
-    pub __isset_vds_nowhere_to_go: u_int8_t,
-    pub vds_flow_queue_limit_exceeded: i64,
-    pub __isset_vds_flow_queue_limit_exceeded: u_int8_t,
-    pub vds_flow_no_memory: i64,
-    pub __isset_vds_flow_no_memory: u_int8_t,
-    pub vds_flow_invalid_protocol: i64,
-    pub __isset_vds_flow_invalid_protocol: u_int8_t,
-    pub vds_flow_nat_no_rflow: i64,
-    pub __isset_vds_flow_nat_no_rflow: u_int8_t,
-    pub vds_flow_action_drop: i64,
-    pub __isset_vds_flow_action_drop: u_int8_t,
-    pub vds_flow_action_invalid: i64,
-    pub __isset_vds_flow_action_invalid: u_int8_t,
-    pub vds_flow_unusable: i64,
-    pub __isset_vds_flow_unusable: u_int8_t,
-    pub vds_flow_table_full: i64,
-    pub __isset_vds_flow_table_full: u_int8_t,
-    pub vds_interface_tx_discard: i64,
-    pub __isset_vds_interface_tx_discard: u_int8_t,
-    pub vds_interface_drop: i64,
-    pub __isset_vds_interface_drop: u_int8_t,
-    pub vds_duplicated: i64,
-    pub __isset_vds_duplicated: u_int8_t,
-    pub vds_push: i64,
-    pub __isset_vds_push: u_int8_t,
-    pub vds_ttl_exceeded: i64,
-    pub __isset_vds_ttl_exceeded: u_int8_t,
-    pub vds_invalid_nh: i64,
-    pub __isset_vds_invalid_nh: u_int8_t,
-    pub vds_invalid_label: i64,
-    pub __isset_vds_invalid_label: u_int8_t,
-    pub vds_invalid_protocol: i64,
-    pub __isset_vds_invalid_protocol: u_int8_t,
-    pub vds_interface_rx_discard: i64,
-    pub __isset_vds_interface_rx_discard: u_int8_t,
-    pub vds_invalid_mcast_source: i64,
-    pub __isset_vds_invalid_mcast_source: u_int8_t,
-    pub vds_head_alloc_fail: i64,
-    pub __isset_vds_head_alloc_fail: u_int8_t,
-    pub vds_pcow_fail: i64,
-    pub __isset_vds_pcow_fail: u_int8_t,
-    pub vds_mcast_df_bit: i64,
-    pub __isset_vds_mcast_df_bit: u_int8_t,
-    pub vds_mcast_clone_fail: i64,
-    pub __isset_vds_mcast_clone_fail: u_int8_t,
-    pub vds_no_memory: i64,
-    pub __isset_vds_no_memory: u_int8_t,
-    pub vds_rewrite_fail: i64,
-    pub __isset_vds_rewrite_fail: u_int8_t,
-    pub vds_misc: i64,
-    pub __isset_vds_misc: u_int8_t,
-    pub vds_invalid_packet: i64,
-    pub __isset_vds_invalid_packet: u_int8_t,
-    pub vds_cksum_err: i64,
-    pub __isset_vds_cksum_err: u_int8_t,
-    pub vds_no_fmd: i64,
-    pub __isset_vds_no_fmd: u_int8_t,
-    pub vds_cloned_original: i64,
-    pub __isset_vds_cloned_original: u_int8_t,
-    pub vds_invalid_vnid: i64,
-    pub __isset_vds_invalid_vnid: u_int8_t,
-    pub vds_frag_err: i64,
-    pub __isset_vds_frag_err: u_int8_t,
-    pub vds_invalid_source: i64,
-    pub __isset_vds_invalid_source: u_int8_t,
-    pub vds_l2_no_route: i64,
-    pub __isset_vds_l2_no_route: u_int8_t,
-    pub vds_fragment_queue_fail: i64,
-    pub __isset_vds_fragment_queue_fail: u_int8_t,
-    pub vds_vlan_fwd_tx: i64,
-    pub __isset_vds_vlan_fwd_tx: u_int8_t,
-    pub vds_vlan_fwd_enq: i64,
-    pub __isset_vds_vlan_fwd_enq: u_int8_t,
-    pub vds_drop_new_flow: i64,
-    pub __isset_vds_drop_new_flow: u_int8_t,
-    pub vds_flow_evict: i64,
-    pub __isset_vds_flow_evict: u_int8_t,
-    pub vds_trap_original: i64,
-    pub __isset_vds_trap_original: u_int8_t,
-    pub vds_leaf_to_leaf: i64,
-    pub __isset_vds_leaf_to_leaf: u_int8_t,
-    pub vds_bmac_isid_mismatch: i64,
-    pub __isset_vds_bmac_isid_mismatch: u_int8_t,
-    pub vds_pkt_loop: i64,
-    pub __isset_vds_pkt_loop: u_int8_t,
-    pub vds_no_crypt_path: i64,
-    pub __isset_vds_no_crypt_path: u_int8_t,
-    pub vds_invalid_hbs_pkt: i64,
-    pub __isset_vds_invalid_hbs_pkt: u_int8_t,
-    pub vds_no_frag_entry: i64,
-    pub __isset_vds_no_frag_entry: u_int8_t,
-    pub vds_icmp_error: i64,
-    pub __isset_vds_icmp_error: u_int8_t,
-    pub vds_clone_fail: i64,
-    pub __isset_vds_clone_fail: u_int8_t,
-}
-pub type vr_drop_stats_req = _vr_drop_stats_req;
-extern "C" {
-    pub fn vr_drop_stats_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_drop_stats_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_drop_stats_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_drop_stats_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_drop_stats_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_drop_stats_req_process(pvr_drop_stats_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_qos_map_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub qmr_rid: u16,
-    pub __isset_qmr_rid: u_int8_t,
-    pub qmr_id: u16,
-    pub __isset_qmr_id: u_int8_t,
-    pub qmr_dscp: *mut i8,
-    pub qmr_dscp_size: u_int32_t,
-    pub __isset_qmr_dscp: u_int8_t,
-    pub qmr_dscp_fc_id: *mut i8,
-    pub qmr_dscp_fc_id_size: u_int32_t,
-    pub __isset_qmr_dscp_fc_id: u_int8_t,
-    pub qmr_mpls_qos: *mut i8,
-    pub qmr_mpls_qos_size: u_int32_t,
-    pub __isset_qmr_mpls_qos: u_int8_t,
-    pub qmr_mpls_qos_fc_id: *mut i8,
-    pub qmr_mpls_qos_fc_id_size: u_int32_t,
-    pub __isset_qmr_mpls_qos_fc_id: u_int8_t,
-    pub qmr_dotonep: *mut i8,
-    pub qmr_dotonep_size: u_int32_t,
-    pub __isset_qmr_dotonep: u_int8_t,
-    pub qmr_dotonep_fc_id: *mut i8,
-    pub qmr_dotonep_fc_id_size: u_int32_t,
-    pub __isset_qmr_dotonep_fc_id: u_int8_t,
-    pub qmr_marker: i16,
-    pub __isset_qmr_marker: u_int8_t,
-}
-pub type vr_qos_map_req = _vr_qos_map_req;
-extern "C" {
-    pub fn vr_qos_map_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_qos_map_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_qos_map_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_qos_map_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_qos_map_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_qos_map_req_process(pvr_qos_map_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_fc_map_req {
-    pub h_op: sandesh_op,
-    pub __isset_h_op: u_int8_t,
-    pub fmr_rid: u16,
-    pub __isset_fmr_rid: u_int8_t,
-    pub fmr_id: *mut i16,
-    pub fmr_id_size: u_int32_t,
-    pub __isset_fmr_id: u_int8_t,
-    pub fmr_dscp: *mut i8,
-    pub fmr_dscp_size: u_int32_t,
-    pub __isset_fmr_dscp: u_int8_t,
-    pub fmr_mpls_qos: *mut i8,
-    pub fmr_mpls_qos_size: u_int32_t,
-    pub __isset_fmr_mpls_qos: u_int8_t,
-    pub fmr_dotonep: *mut i8,
-    pub fmr_dotonep_size: u_int32_t,
-    pub __isset_fmr_dotonep: u_int8_t,
-    pub fmr_queue_id: *mut i8,
-    pub fmr_queue_id_size: u_int32_t,
-    pub __isset_fmr_queue_id: u_int8_t,
-    pub fmr_marker: i16,
-    pub __isset_fmr_marker: u_int8_t,
-}
-pub type vr_fc_map_req = _vr_fc_map_req;
-extern "C" {
-    pub fn vr_fc_map_req_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_fc_map_req_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_fc_map_req_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_fc_map_req_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_fc_map_req_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_fc_map_req_process(pvr_fc_map_req: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_flow_response {
-    pub fresp_op: flow_op,
-    pub __isset_fresp_op: u_int8_t,
-    pub fresp_rid: u16,
-    pub __isset_fresp_rid: u_int8_t,
-    pub fresp_flags: u16,
-    pub __isset_fresp_flags: u_int8_t,
-    pub fresp_index: u32,
-    pub __isset_fresp_index: u_int8_t,
-    pub fresp_bytes: u32,
-    pub __isset_fresp_bytes: u_int8_t,
-    pub fresp_packets: u32,
-    pub __isset_fresp_packets: u_int8_t,
-    pub fresp_stats_oflow: u32,
-    pub __isset_fresp_stats_oflow: u_int8_t,
-    pub fresp_gen_id: i8,
-    pub __isset_fresp_gen_id: u_int8_t,
-}
-pub type vr_flow_response = _vr_flow_response;
-extern "C" {
-    pub fn vr_flow_response_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_response_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_response_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_response_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_response_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_flow_response_process(pvr_flow_response: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_flow_table_data {
-    pub ftable_op: flow_op,
-    pub __isset_ftable_op: u_int8_t,
-    pub ftable_rid: u16,
-    pub __isset_ftable_rid: u_int8_t,
-    pub ftable_size: u32,
-    pub __isset_ftable_size: u_int8_t,
-    pub ftable_dev: u16,
-    pub __isset_ftable_dev: u_int8_t,
-    pub ftable_file_path: *mut ::std::os::raw::c_char,
-    pub __isset_ftable_file_path: u_int8_t,
-    pub ftable_used_entries: u64,
-    pub __isset_ftable_used_entries: u_int8_t,
-    pub ftable_processed: u64,
-    pub __isset_ftable_processed: u_int8_t,
-    pub ftable_deleted: u64,
-    pub __isset_ftable_deleted: u_int8_t,
-    pub ftable_added: u64,
-    pub __isset_ftable_added: u_int8_t,
-    pub ftable_created: u64,
-    pub __isset_ftable_created: u_int8_t,
-    pub ftable_changed: u64,
-    pub __isset_ftable_changed: u_int8_t,
-    pub ftable_hold_oflows: u32,
-    pub __isset_ftable_hold_oflows: u_int8_t,
-    pub ftable_cpus: u32,
-    pub __isset_ftable_cpus: u_int8_t,
-    pub ftable_oflow_entries: u32,
-    pub __isset_ftable_oflow_entries: u_int8_t,
-    pub ftable_hold_stat: *mut u32,
-    pub ftable_hold_stat_size: u_int32_t,
-    pub __isset_ftable_hold_stat: u_int8_t,
-    pub ftable_burst_free_tokens: u32,
-    pub __isset_ftable_burst_free_tokens: u_int8_t,
-    pub ftable_hold_entries: u32,
-    pub __isset_ftable_hold_entries: u_int8_t,
-}
-pub type vr_flow_table_data = _vr_flow_table_data;
-extern "C" {
-    pub fn vr_flow_table_data_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_table_data_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_table_data_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_table_data_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_flow_table_data_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_flow_table_data_process(pvr_flow_table_data: *mut ::std::os::raw::c_void);
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_bridge_table_data {
-    pub btable_op: sandesh_op,
-    pub __isset_btable_op: u_int8_t,
-    pub btable_rid: u16,
-    pub __isset_btable_rid: u_int8_t,
-    pub btable_size: u32,
-    pub __isset_btable_size: u_int8_t,
-    pub btable_dev: u16,
-    pub __isset_btable_dev: u_int8_t,
-    pub btable_file_path: *mut ::std::os::raw::c_char,
-    pub __isset_btable_file_path: u_int8_t,
-}
-pub type vr_bridge_table_data = _vr_bridge_table_data;
-extern "C" {
-    pub fn vr_bridge_table_data_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_bridge_table_data_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_bridge_table_data_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_bridge_table_data_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_bridge_table_data_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_bridge_table_data_process(
-        pvr_bridge_table_data: *mut ::std::os::raw::c_void,
-    );
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct _vr_hugepage_config {
-    pub vhp_op: sandesh_op,
-    pub __isset_vhp_op: u_int8_t,
-    pub vhp_mem: *mut u64,
-    pub vhp_mem_size: u_int32_t,
-    pub __isset_vhp_mem: u_int8_t,
-    pub vhp_psize: *mut u32,
-    pub vhp_psize_size: u_int32_t,
-    pub __isset_vhp_psize: u_int8_t,
-    pub vhp_resp: u32,
-    pub __isset_vhp_resp: u_int8_t,
-    pub vhp_mem_sz: *mut u32,
-    pub vhp_mem_sz_size: u_int32_t,
-    pub __isset_vhp_mem_sz: u_int8_t,
-    pub vhp_file_paths: *mut i8,
-    pub vhp_file_paths_size: u_int32_t,
-    pub __isset_vhp_file_paths: u_int8_t,
-    pub vhp_file_path_sz: *mut u32,
-    pub vhp_file_path_sz_size: u_int32_t,
-    pub __isset_vhp_file_path_sz: u_int8_t,
-}
-pub type vr_hugepage_config = _vr_hugepage_config;
-extern "C" {
-    pub fn vr_hugepage_config_write(
-        wsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_hugepage_config_write_binary_to_buffer(
-        wsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_hugepage_config_read(
-        rsandesh: *mut ::std::os::raw::c_void,
-        protocol: *mut ThriftProtocol,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_hugepage_config_read_binary_from_buffer(
-        rsandesh: *mut ::std::os::raw::c_void,
-        buf: *mut u8,
-        buf_len: usize,
-        error: *mut ::std::os::raw::c_int,
-    ) -> i32;
-}
-extern "C" {
-    pub fn vr_hugepage_config_free(fsandesh: *mut ::std::os::raw::c_void);
-}
-extern "C" {
-    pub fn vr_hugepage_config_process(pvr_hugepage_config: *mut ::std::os::raw::c_void);
-}
 extern "C" {
     pub fn vr_find_sandesh_info(
         sname: *const ::std::os::raw::c_char,
@@ -0,0 +1,91 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+// Field layouts of the sandesh objects, generated at build time from
+// priv/vr.sandesh as `sandesh!` and `vr_sandesh!` tables for their C structs.
+
+use super::binary_protocol::{BinaryReader, BinaryWriter, SandeshStruct};
+use super::error::CodecError;
+use super::vr_types::VrSandesh;
+use super::vr_types_binding::*;
+#[cfg(not(feature = "native-codec"))]
+use crate::utils;
+
+use std::ffi::CStr;
+use std::mem::size_of;
+#[cfg(not(feature = "native-codec"))]
+use std::os::raw::{c_int, c_void};
+
+/// Implements `VrSandesh` for a C struct laid out by `sandesh!`, given its
+/// `write_binary_to_buffer` and `read_binary_from_buffer` functions.
+macro_rules! vr_sandesh {
+    ($sandesh:ident($write:ident, $read:ident) {
+        $($id:literal: $kind:ident $(<$elem:ident>)? $field:ident $([$size:ident])?;)*
+    }) => {
+        impl Default for $sandesh {
+            fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+        }
+
+        impl VrSandesh for $sandesh {
+            type Type = $sandesh;
+
+            fn new() -> Self { $sandesh::default() }
+
+            // The headers fit in 4 times the struct, the lists and strings
+            // it points to come on top
+            fn obj_len(&self) -> usize {
+                let mut size = 4 * size_of::<Self>();
+                $(size += vr_sandesh_len!(
+                    $kind $(<$elem>)?, self.$field $(, self.$size)?
+                );)*
+                size
+            }
+
+            #[cfg(not(feature = "native-codec"))]
+            fn as_c_void(&self) -> *mut c_void {
+                utils::into_raw_ptr(&*self) as *mut c_void
+            }
+
+            #[cfg(not(feature = "native-codec"))]
+            fn write_binary_fn(
+                &self,
+            ) -> unsafe extern "C" fn(
+                wsandesh: *mut c_void,
+                buf: *mut u8,
+                len: usize,
+                err: *mut c_int,
+            ) -> i32 {
+                $write
+            }
+
+            #[cfg(not(feature = "native-codec"))]
+            fn read_binary_fn(
+                &self,
+            ) -> unsafe extern "C" fn(
+                rsandesh: *mut c_void,
+                buf: *mut u8,
+                len: usize,
+                err: *mut c_int,
+            ) -> i32 {
+                $read
+            }
+        }
+    };
+}
+
+macro_rules! vr_sandesh_len {
+    (string, $value:expr) => {
+        match $value {
+            s if s.is_null() => 0,
+            s => unsafe { CStr::from_ptr(s).to_bytes().len() },
+        }
+    };
+    (list<$elem:ident>, $list:expr, $size:expr) => {
+        $size as usize * size_of::<sandesh_type!($elem)>()
+    };
+    ($kind:ident $(<$enum:ident>)?, $value:expr) => {
+        0
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/vr_types_fields.rs"));
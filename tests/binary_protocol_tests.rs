@@ -0,0 +1,139 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_binary_protocol {
+    use vr_type::vr_messages::binary_protocol::{read_sandesh, read_sandesh_rxfer};
+    use vr_type::vr_messages::binary_protocol::{sandesh_len, write_sandesh};
+    use vr_type::vr_messages::binary_protocol::MAX_SKIP_DEPTH;
+    use vr_type::vr_messages::vr_types::VrSandesh;
+    use vr_type::vr_messages::vr_types_binding::*;
+    use vr_type::vr_messages::CodecError;
+
+    #[test]
+    fn mpls_wire_format() {
+        let mut mr = vr_mpls_req::new();
+        mr.h_op = 1;
+        mr.mr_label = 0x10;
+        mr.mr_rid = -1;
+        mr.mr_nhid = 7;
+        let bytes = write_sandesh(&mr);
+        let mut expected = vec![0, 0, 0, 11];
+        expected.extend_from_slice(b"vr_mpls_req");
+        expected.extend_from_slice(&[8, 0, 1, 0, 0, 0, 1]);
+        expected.extend_from_slice(&[8, 0, 2, 0, 0, 0, 0x10]);
+        expected.extend_from_slice(&[6, 0, 3, 0xff, 0xff]);
+        expected.extend_from_slice(&[8, 0, 4, 0, 0, 0, 7]);
+        expected.extend_from_slice(&[8, 0, 5, 0, 0, 0, 0]);
+        expected.push(0);
+        assert_eq!(expected, bytes);
+    }
+
+    #[test]
+    fn lists_and_strings() {
+        let ids: Vec<i16> = vec![1, -2, 3];
+        let mut fmr = vr_fc_map_req::new();
+        fmr.fmr_id = ids.as_ptr() as *mut i16;
+        fmr.fmr_id_size = ids.len() as u32;
+        let bytes = write_sandesh(&fmr);
+        assert_eq!(75 + 3 * 2, bytes.len());
+
+        let mut decoder = vr_fc_map_req::new();
+        assert_eq!(bytes.len(), read_sandesh(&mut decoder, &bytes).unwrap());
+        assert_eq!(3, decoder.fmr_id_size);
+        let decoded = unsafe { std::slice::from_raw_parts(decoder.fmr_id, 3) };
+        assert_eq!(&ids[..], decoded);
+
+        let path = std::ffi::CString::new("/dev/flow").unwrap();
+        let mut ftable = vr_flow_table_data::new();
+        ftable.ftable_file_path = path.as_ptr() as *mut _;
+        let bytes = write_sandesh(&ftable);
+        let mut decoder = vr_flow_table_data::new();
+        read_sandesh(&mut decoder, &bytes).unwrap();
+        let decoded = unsafe { std::ffi::CStr::from_ptr(decoder.ftable_file_path) };
+        assert_eq!(path.as_c_str(), decoded);
+    }
+
+    #[test]
+    fn byte_list_rxfer() {
+        let mac: Vec<i8> = vec![0, 1, 2, 3, 4, 5];
+        let mut rtr = vr_route_req::new();
+        rtr.rtr_mac = mac.as_ptr() as *mut i8;
+        rtr.rtr_mac_size = mac.len() as u32;
+        let bytes = write_sandesh(&rtr);
+        assert_eq!(120, bytes.len());
+
+        // The C reader leaves the elements of byte lists out of its count
+        let mut decoder = vr_route_req::new();
+        assert_eq!(114, read_sandesh_rxfer(&mut decoder, &bytes).unwrap());
+        assert_eq!(120, read_sandesh(&mut decoder, &bytes).unwrap());
+    }
+
+    #[test]
+    fn skip_unknown_fields() {
+        let mut resp = vr_response::new();
+        resp.resp_code = -17;
+        let mut bytes = write_sandesh(&resp);
+        let stop = bytes.pop().unwrap();
+        // A string and a list<i32> from a newer vrouter
+        bytes.extend_from_slice(&[11, 0, 3, 0, 0, 0, 2, b'o', b'k']);
+        bytes.extend_from_slice(&[15, 0, 4, 8, 0, 0, 0, 1, 0, 0, 0, 1]);
+        bytes.push(stop);
+        // and the next sandesh of the reply
+        bytes.extend_from_slice(&write_sandesh(&resp));
+
+        let mut decoder = vr_response::new();
+        assert_eq!(30 + 9 + 12, read_sandesh(&mut decoder, &bytes).unwrap());
+        assert_eq!(-17, decoder.resp_code);
+    }
+
+    #[test]
+    fn truncated_buffer() {
+        let bytes = write_sandesh(&vr_vxlan_req::new());
+        for len in 0..bytes.len() {
+            match read_sandesh(&mut vr_vxlan_req::new(), &bytes[..len]) {
+                Err(CodecError::Read(_)) => (),
+                result => panic!("read {} bytes: {:?}", len, result),
+            }
+        }
+    }
+
+    // A vr_response with an unknown field of `depth` lists, one in another
+    fn nested_lists(depth: usize) -> Vec<u8> {
+        let mut bytes = write_sandesh(&vr_response::new());
+        let stop = bytes.pop().unwrap();
+        bytes.extend_from_slice(&[15, 0, 9]);
+        for _ in 1..depth {
+            bytes.extend_from_slice(&[15, 0, 0, 0, 1]);
+        }
+        bytes.extend_from_slice(&[8, 0, 0, 0, 0]);
+        bytes.push(stop);
+        bytes
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let bytes = nested_lists(MAX_SKIP_DEPTH - 1);
+        assert_eq!(bytes.len(), sandesh_len(&bytes).unwrap());
+        assert_eq!(bytes.len(), read_sandesh(&mut vr_response::new(), &bytes).unwrap());
+
+        let bytes = nested_lists(100_000);
+        match sandesh_len(&bytes) {
+            Err(CodecError::Read(libc::EINVAL)) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        match read_sandesh(&mut vr_response::new(), &bytes) {
+            Err(CodecError::Read(libc::EINVAL)) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn another_sandesh() {
+        let bytes = write_sandesh(&vr_vxlan_req::new());
+        match read_sandesh(&mut vr_mpls_req::new(), &bytes) {
+            Err(CodecError::InvalidSandeshName) => (),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_buffers {
+    use eui48::MacAddress;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use vr_type::vr_messages::*;
+
+    // Counts the bytes allocated and not freed yet by the current thread, so
+    // that the test harness running on other threads does not get in the way.
+    struct CountingAllocator;
+
+    thread_local! {
+        static LIVE_BYTES: Cell<isize> = Cell::new(0);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = LIVE_BYTES.try_with(|n| n.set(n.get() + layout.size() as isize));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            let _ = LIVE_BYTES.try_with(|n| n.set(n.get() - layout.size() as isize));
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn live_bytes() -> isize { LIVE_BYTES.with(|n| n.get()) }
+
+    // The C codec allocates its strings and lists with malloc, out of sight of
+    // the allocator above, so the bytes in use by malloc are checked as well.
+    fn malloc_bytes() -> usize { unsafe { libc::mallinfo2().uordblks } }
+
+    fn messages() -> Vec<Message> {
+        let mac = MacAddress::new([0x00, 0x05, 0x85, 0x00, 0x00, 0x01]);
+        let ip6 = Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1);
+
+        let mut btable = BridgeTableData::default();
+        btable.file_path = "/dev/shm/bridge".to_string();
+        let mut fmr = FcMapRequest::default();
+        fmr.id = vec![1, 2];
+        fmr.dscp = vec![3, 4];
+        fmr.mpls_qos = vec![5];
+        fmr.dotonep = vec![6];
+        fmr.queue_id = vec![7];
+        let mut fr = FlowRequest::default();
+        fr.flow_sip = Some(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+        fr.flow_dip = Some(IpAddr::V6(ip6));
+        fr.pcap_meta_data = vec![1, 2, 3];
+        let mut ftable = FlowTableData::default();
+        ftable.file_path = "/dev/shm/flow".to_string();
+        ftable.hold_stat = vec![1, 2, 3, 4];
+        let mut vhp = HugepageConfig::default();
+        vhp.mem = vec![1 << 30];
+        vhp.psize = vec![2048];
+        vhp.mem_size = vec![1];
+        vhp.file_paths = vec![0x2f, 0x6d];
+        vhp.file_path_size = vec![2];
+        let mut vdu = InfoRequest::dump(InfoMsg::Mempool);
+        vdu.inbuf = vec![1, 2];
+        vdu.proc_info = vec![0x61, 0x62, 0];
+        let mut vifr = InterfaceRequest::default();
+        vifr.name = "tap0".to_string();
+        vifr.queue_ierrors_to_lcore = vec![1, 2];
+        vifr.mac = mac;
+        vifr.src_mac = mac;
+        vifr.pbb_mac = mac;
+        vifr.bridge_idx = vec![1];
+        vifr.fat_flow_protocol_port = vec![80, 443];
+        vifr.in_mirror_md = vec![1];
+        vifr.out_mirror_md = vec![2];
+        vifr.hw_queues = vec![0, 1];
+        vifr.if_guid = vec![1; 16];
+        vifr.fat_flow_exclude_ip_list = vec![Ipv4Addr::new(10, 0, 0, 1)];
+        vifr.fat_flow_exclude_ip6_list = vec![ip6];
+        vifr.fat_flow_exclude_ip6_plen_list = vec![64];
+        vifr.fat_flow_src_prefix = vec![1 << 64 | 1];
+        vifr.fat_flow_src_prefix_mask = vec![24];
+        vifr.fat_flow_src_aggregate_plen = vec![16];
+        vifr.fat_flow_dst_prefix = vec![2];
+        vifr.fat_flow_dst_prefix_mask = vec![24];
+        vifr.fat_flow_dst_aggregate_plen = vec![16];
+        vifr.fab_name = "eth0".to_string();
+        vifr.fab_drv_name = "virtio".to_string();
+        vifr.bond_slave_name = "eth1".to_string();
+        vifr.bond_slave_drv_name = "ixgbe".to_string();
+        vifr.vlan_name = "vlan100".to_string();
+        let mut nhr = NexthopRequest::default();
+        nhr.encap = vec![1, 2, 3, 4, 5, 6];
+        nhr.nh_list = vec![1, 2];
+        nhr.label_list = vec![3, 4];
+        nhr.tun_sip6 = ip6;
+        nhr.tun_dip6 = ip6;
+        nhr.pbb_mac = mac;
+        nhr.rw_dst_mac = mac;
+        let mut vdl = PktDropLog::default();
+        vdl.pkt_droplog_arr = vec![1, 2, 3];
+        let mut qmr = QosMapRequest::default();
+        qmr.dscp = vec![1];
+        qmr.dscp_fc_id = vec![2];
+        qmr.mpls_qos = vec![3];
+        qmr.mpls_qos_fc_id = vec![4];
+        qmr.dotonep = vec![5];
+        qmr.dotonep_fc_id = vec![6];
+        let mut rtr = RouteRequest::default();
+        rtr.family = libc::AF_INET6;
+        rtr.prefix = Some(IpAddr::V6(ip6));
+        rtr.marker = Some(IpAddr::V6(ip6));
+        rtr.mac = mac;
+        let mut vo = VrouterOps::default();
+        vo.build_info = "{\"build-info\": []}".to_string();
+        vo.log_type_enable = vec![1, 2];
+        vo.log_type_disable = vec![3];
+
+        vec![
+            Message::BridgeTableData(btable),
+            Message::DropStats(DropStats::default()),
+            Message::FcMapRequest(fmr),
+            Message::FlowRequest(fr),
+            Message::FlowResponse(FlowResponse::default()),
+            Message::FlowTableData(ftable),
+            Message::HugepageConfig(vhp),
+            Message::InfoRequest(vdu),
+            Message::InterfaceRequest(vifr),
+            Message::MemStatsRequest(MemStatsRequest::default()),
+            Message::MirrorRequest(MirrorRequest::default()),
+            Message::MplsRequest(MplsRequest::default()),
+            Message::NexthopRequest(nhr),
+            Message::PktDropLog(vdl),
+            Message::QosMapRequest(qmr),
+            Message::VrResponse(VrResponse::default()),
+            Message::RouteRequest(rtr),
+            Message::VrfRequest(VrfRequest::default()),
+            Message::VrfAssignRequest(VrfAssignRequest::default()),
+            Message::VrfStatsRequest(VrfStatsRequest::default()),
+            Message::VxlanRequest(VxlanRequest::default()),
+            Message::VrouterOps(vo),
+        ]
+    }
+
+    fn round_trip(messages: &[Message]) {
+        for message in messages {
+            let bytes = message.to_bytes().unwrap();
+            Message::from_bytes(bytes).unwrap();
+        }
+    }
+
+    #[test]
+    fn encode_and_decode_without_leaking() {
+        let messages = messages();
+        // malloc takes a couple of rounds to settle the chunks it caches
+        for _ in 0..2 {
+            round_trip(&messages);
+        }
+
+        let before = (live_bytes(), malloc_bytes());
+        for _ in 0..1000 {
+            round_trip(&messages);
+        }
+        assert_eq!(before, (live_bytes(), malloc_bytes()));
+    }
+}
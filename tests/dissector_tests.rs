@@ -0,0 +1,120 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_dissector {
+    use std::net::{IpAddr, Ipv4Addr};
+    use vr_type::vr_messages::dissector::{self, FieldValue};
+    use vr_type::vr_messages::vr_types_binding::{
+        ThriftType_T_BYTE, ThriftType_T_I16, ThriftType_T_I32, ThriftType_T_LIST,
+    };
+    use vr_type::vr_messages::*;
+
+    #[test]
+    fn dissect_fields() {
+        let mut vdu = InfoRequest::dump(InfoMsg::Stats);
+        vdu.marker = 2;
+        vdu.inbuf = vec![1, 2, 3];
+        let bytes = Message::InfoRequest(vdu).to_bytes().unwrap();
+        let dissection = dissector::dissect(&bytes).unwrap();
+
+        assert_eq!(dissection.name, "vr_info_req");
+        assert_eq!(dissection.bytes.len(), bytes.len());
+        assert_eq!(dissection.fields.len(), 9);
+
+        // The name takes 4 + 11 bytes, then 7 for the i32 op
+        let op = &dissection.fields[0];
+        assert_eq!((op.id, op.name), (1, Some("h_op")));
+        assert_eq!((op.ty, op.offset, op.length), (ThriftType_T_I32, 15, 7));
+        assert_eq!(op.value, FieldValue::I32(SANDESH_OP_DUMP as i32));
+
+        let marker = &dissection.fields[4];
+        assert_eq!((marker.id, marker.name), (5, Some("vdu_marker")));
+        assert_eq!(
+            (marker.ty, marker.offset, marker.length),
+            (ThriftType_T_I16, 37, 5)
+        );
+        assert_eq!(marker.value, FieldValue::I16(2));
+
+        let inbuf = &dissection.fields[7];
+        assert_eq!((inbuf.id, inbuf.name), (8, Some("vdu_inbuf")));
+        assert_eq!((inbuf.ty, inbuf.length), (ThriftType_T_LIST, 11));
+        assert_eq!(
+            inbuf.value,
+            FieldValue::List {
+                elem_ty: ThriftType_T_BYTE,
+                elems: vec![
+                    FieldValue::Byte(1),
+                    FieldValue::Byte(2),
+                    FieldValue::Byte(3)
+                ],
+            }
+        );
+        assert_eq!(inbuf.to_string(), "8 vdu_inbuf: list<byte>[3] = [1, 2, 3]");
+    }
+
+    #[test]
+    fn dissect_addresses() {
+        let mut rtr = RouteRequest::default();
+        rtr.family = libc::AF_INET;
+        rtr.prefix = Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)));
+        let bytes = Message::RouteRequest(rtr).to_bytes().unwrap();
+        let dissection = dissector::dissect(&bytes).unwrap();
+
+        let prefix = dissection
+            .fields
+            .iter()
+            .find(|field| field.name == Some("rtr_prefix"))
+            .unwrap();
+        let octets = [10, 0, 0, 0].iter().map(|&b| FieldValue::Byte(b)).collect();
+        assert_eq!(
+            prefix.value,
+            FieldValue::List {
+                elem_ty: ThriftType_T_BYTE,
+                elems: octets,
+            }
+        );
+    }
+
+    #[test]
+    fn hex_dump() {
+        let bytes = Message::VrResponse(VrResponse::default())
+            .to_bytes()
+            .unwrap();
+        let dump = dissector::dissect(&bytes).unwrap().to_string();
+        let expected = "\
+vr_response (30 bytes)
+0000  00 00 00 0b 76 72 5f 72 65 73 70 6f 6e 73 65     name \"vr_response\"
+000f  08 00 01 00 00 00 00                             1 h_op: i32 = 0
+0016  08 00 02 00 00 00 00                             2 resp_code: i32 = 0
+001d  00                                               stop
+";
+        assert_eq!(dump, expected);
+    }
+
+    #[test]
+    fn truncated_buffer() {
+        let bytes = Message::VrResponse(VrResponse::default())
+            .to_bytes()
+            .unwrap();
+        assert!(dissector::dissect(&bytes[..20]).is_err());
+        assert!(dissector::dissect(&bytes[..2]).is_err());
+    }
+
+    #[test]
+    fn deeply_nested_lists() {
+        let mut bytes = Message::VrResponse(VrResponse::default())
+            .to_bytes()
+            .unwrap();
+        let stop = bytes.pop().unwrap();
+        bytes.extend_from_slice(&[15, 0, 9]);
+        for _ in 0..100_000 {
+            bytes.extend_from_slice(&[15, 0, 0, 0, 1]);
+        }
+        bytes.push(stop);
+        match dissector::dissect(&bytes) {
+            Err(CodecError::Read(libc::EINVAL)) => (),
+            result => panic!("unexpected result: {:?}", result.map(|d| d.fields)),
+        }
+    }
+}
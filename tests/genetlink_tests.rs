@@ -3,12 +3,204 @@
 
 #[cfg(test)]
 mod genetlink_test {
-    use vr_type::genetlink::resolve_family_id;
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+    use std::{env, fs, process, thread};
+    use vr_type::genetlink::{get_family, list_families, resolve_family_id};
+    use vr_type::genetlink::{AsyncVrouterClient, GenericNetlinkMessage};
+    use vr_type::genetlink::MessageHandleError;
+    use vr_type::genetlink::{decode_capture, CapturedPayload};
+    use vr_type::genetlink::{Transport, VrouterPool, VrouterUnixSocket};
+    use vr_type::netlink::pcap::{Direction, PcapWriter};
+    use vr_type::genetlink::{CTRL_CMD_GETFAMILY, GENL_ID_CTRL};
+    use vr_type::netlink::{NetlinkAttr, NetlinkMessage, Serialize, NLM_F_REQUEST};
+    use vr_type::netlink::NetlinkError;
+    use vr_type::vr_messages::*;
 
     #[test]
     fn resolve_family_id_test() {
         let result = resolve_family_id("TASKSTATS");
         assert_eq!(result.is_ok(), true);
     }
-}
 
+    #[test]
+    fn get_family_test() {
+        let nlctrl = get_family("nlctrl").unwrap();
+        assert_eq!(nlctrl.id, GENL_ID_CTRL);
+        assert_eq!(nlctrl.name, "nlctrl");
+        assert!(nlctrl.has_op(CTRL_CMD_GETFAMILY as u32));
+        assert!(nlctrl.mcast_groups.iter().any(|group| group.name == "notify"));
+        match get_family("no-such-family") {
+            Err(NetlinkError::FamilyNotFound(name)) => assert_eq!(name, "no-such-family"),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn list_families_test() {
+        let families = list_families().unwrap();
+        assert!(families.iter().any(|family| family.name == "nlctrl"));
+        assert!(families.iter().all(|family| family.id >= GENL_ID_CTRL));
+    }
+
+    #[tokio::test]
+    async fn async_client_test() {
+        match AsyncVrouterClient::new().await {
+            Ok(client) => assert!(client.family_id() > 0),
+            // If the vrouter.ko has not inserted, the family lookup is rejected.
+            Err(MessageHandleError::FamilyNotFound(name)) => assert_eq!(name, "vrouter"),
+            Err(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn unix_socket_test() {
+        let path = env::temp_dir().join(format!("vr_dpdk_netlink.{}", process::id()));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        // Stands in for the DPDK vrouter: checks the framing of the request
+        // and rejects it with an NLMSG_ERROR carrying -ENOENT.
+        let vrouter = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut header = [0u8; 16];
+            stream.read_exact(&mut header).unwrap();
+            let len = u32::from_ne_bytes([header[0], header[1], header[2], header[3]]);
+            let mut payload = vec![0; len as usize - header.len()];
+            stream.read_exact(&mut payload).unwrap();
+            // genlmsghdr: SANDESH_REQUEST, version 2
+            assert_eq!(&payload[..2], &[1, 2]);
+            // nlattr: NL_ATTR_VR_MESSAGE_PROTOCOL
+            assert_eq!(&payload[6..8], &1u16.to_ne_bytes());
+            let mut reply = Vec::new();
+            reply.extend(&(16u32 + 4 + 16).to_ne_bytes());
+            reply.extend(&2u16.to_ne_bytes());
+            reply.extend(&0u16.to_ne_bytes());
+            reply.extend(&header[8..12]);
+            reply.extend(&0u32.to_ne_bytes());
+            reply.extend(&(-libc::ENOENT).to_ne_bytes());
+            reply.extend(&header);
+            stream.write_all(&reply).unwrap();
+        });
+
+        let socket = VrouterUnixSocket::connect(&path).unwrap();
+        let mut vrouter_ops = VrouterOps::default();
+        vrouter_ops.op = SandeshOp::Get;
+        match socket.send(&Message::VrouterOps(vrouter_ops)) {
+            Err(MessageHandleError::KernelError(ack)) => {
+                assert_eq!(ack.errno, libc::ENOENT)
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+        vrouter.join().unwrap();
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn unix_socket_transport_test() {
+        let path = env::temp_dir().join(format!("vr_dpdk_transport.{}", process::id()));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        // Stands in for the DPDK vrouter: echoes the sandesh payload back
+        // in a reply split over two NLM_F_MULTI messages.
+        let vrouter = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut header = [0u8; 16];
+            stream.read_exact(&mut header).unwrap();
+            let len = u32::from_ne_bytes([header[0], header[1], header[2], header[3]]);
+            let mut payload = vec![0; len as usize - header.len()];
+            stream.read_exact(&mut payload).unwrap();
+            for flags in &[libc::NLM_F_MULTI as u16, 0] {
+                let mut reply = Vec::new();
+                reply.extend(&(16 + payload.len() as u32).to_ne_bytes());
+                reply.extend(&header[4..6]);
+                reply.extend(&flags.to_ne_bytes());
+                reply.extend(&header[8..12]);
+                reply.extend(&0u32.to_ne_bytes());
+                reply.extend(&payload);
+                stream.write_all(&reply).unwrap();
+            }
+        });
+
+        let socket = VrouterUnixSocket::connect(&path).unwrap();
+        let replies = socket.request(&[0, 0, 0, 4]).unwrap();
+        assert_eq!(replies, vec![vec![0, 0, 0, 4], vec![0, 0, 0, 4]]);
+        vrouter.join().unwrap();
+        let _ = fs::remove_file(&path);
+    }
+
+    // Echoes the request back after a while, counting overlapping requests.
+    struct SlowSession {
+        in_flight: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    }
+
+    impl Transport for SlowSession {
+        fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(in_flight, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(vec![payload.to_vec()])
+        }
+    }
+
+    #[test]
+    fn pool_test() {
+        fn assert_shareable<T: Send + Sync>() {}
+        assert_shareable::<VrouterPool>();
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let sessions = (0..3)
+            .map(|_| SlowSession {
+                in_flight: in_flight.clone(),
+                peak: peak.clone(),
+            })
+            .collect();
+        let pool = Arc::new(VrouterPool::from_sessions(sessions));
+        let workers: Vec<_> = (0..8u8)
+            .map(|n| {
+                let pool = pool.clone();
+                thread::spawn(move || pool.request(&[n]).unwrap())
+            })
+            .collect();
+        for (n, worker) in workers.into_iter().enumerate() {
+            assert_eq!(worker.join().unwrap(), vec![vec![n as u8]]);
+        }
+        // Requests overlapped, but never more than there are sessions
+        assert!(peak.load(Ordering::SeqCst) > 1);
+        assert!(peak.load(Ordering::SeqCst) <= pool.size());
+    }
+
+    #[test]
+    fn decode_capture_test() {
+        let mut request = VrouterOps::default();
+        request.op = SandeshOp::Get;
+        let sandesh = Message::VrouterOps(request).to_bytes().unwrap();
+        let attrs = [NetlinkAttr::new(1, sandesh)];
+        let genl_msg = GenericNetlinkMessage::new(1, 2, &attrs[..]);
+        let nl_msg = NetlinkMessage::new(0x20, NLM_F_REQUEST, genl_msg).with_seq(7, 42);
+        let mut datagram = vec![0; nl_msg.len() as usize];
+        nl_msg.serialize(&mut datagram[..]);
+
+        let mut writer = PcapWriter::new(vec![]).unwrap();
+        let now = SystemTime::now();
+        writer.write_frame(now, Direction::Outgoing, 16, &datagram).unwrap();
+        // Not generic netlink, left out of the timeline
+        writer.write_frame(now, Direction::Outgoing, 0, &datagram).unwrap();
+        let timeline = decode_capture(&writer.into_inner()[..]).unwrap();
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline[0].direction, Direction::Outgoing);
+        assert_eq!(timeline[0].seq, 7);
+        match &timeline[0].payload {
+            CapturedPayload::Sandesh(messages) => match &messages[..] {
+                [Message::VrouterOps(ops)] => assert_eq!(ops.op, SandeshOp::Get),
+                messages => panic!("unexpected messages: {:?}", messages),
+            },
+            payload => panic!("unexpected payload: {:?}", payload),
+        }
+    }
+}
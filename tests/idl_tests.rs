@@ -0,0 +1,78 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_idl {
+    use std::net::Ipv4Addr;
+    use vr_type::vr_messages::idl::SandeshType;
+    use vr_type::vr_messages::{
+        CodecError, Message, MessageType, NexthopRequest, NhType, SandeshOp,
+    };
+
+    #[test]
+    fn sandesh_types() {
+        assert_eq!(22, SandeshType::ALL.len());
+        for &sandesh_type in SandeshType::ALL {
+            assert_eq!(
+                Some(sandesh_type),
+                SandeshType::from_name(sandesh_type.name())
+            );
+        }
+        assert_eq!(
+            Some(SandeshType::VrInfoReq),
+            SandeshType::from_name("vr_info_req")
+        );
+        assert_eq!(None, SandeshType::from_name("vr_unknown_req"));
+    }
+
+    #[test]
+    fn message_types() {
+        for &sandesh_type in SandeshType::ALL {
+            assert_ne!(MessageType::Unknown, MessageType::from(sandesh_type));
+        }
+        assert_eq!(
+            MessageType::NexthopRequest,
+            MessageType::from(SandeshType::VrNexthopReq)
+        );
+        assert_eq!(
+            MessageType::VrouterOps,
+            MessageType::from(SandeshType::VrouterOps)
+        );
+    }
+
+    #[test]
+    fn decode_message() {
+        let mut nhr = NexthopRequest::default();
+        nhr.op = SandeshOp::Get;
+        nhr._type = NhType::Tunnel;
+        nhr.id = 12;
+        nhr.tun_dip = Ipv4Addr::new(192, 168, 0, 1);
+        nhr.nh_list = vec![1, 2, 3];
+        let bytes = Message::NexthopRequest(nhr).to_bytes().unwrap();
+
+        let message = Message::from_slice(&bytes).unwrap();
+        assert_eq!(MessageType::NexthopRequest, message.message_type());
+        assert_eq!(SandeshOp::Get, message.sandesh_op());
+        assert_eq!(bytes, message.to_bytes().unwrap());
+        match message {
+            Message::NexthopRequest(nhr) => {
+                assert_eq!(NhType::Tunnel, nhr._type);
+                assert_eq!(12, nhr.id);
+                assert_eq!(Ipv4Addr::new(192, 168, 0, 1), nhr.tun_dip);
+                assert_eq!(vec![1, 2, 3], nhr.nh_list);
+            }
+            message => panic!("decoded {:?}", message.message_type()),
+        }
+    }
+
+    #[test]
+    fn decode_unknown() {
+        let mut bytes = vec![0, 0, 0, 14];
+        bytes.extend_from_slice(b"vr_unknown_req");
+        bytes.push(0);
+        match Message::from_slice(&bytes) {
+            Err(CodecError::UnknownMessageType) => (),
+            result => panic!("decoded {:?}", result),
+        }
+    }
+}
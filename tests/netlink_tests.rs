@@ -0,0 +1,346 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod netlink_test {
+    use netlink_sys::{Protocol, Socket};
+    use std::ffi::CString;
+    use std::io::{self, Write};
+    use std::os::unix::fs::MetadataExt;
+    use std::path::PathBuf;
+    use std::sync::mpsc;
+    use std::{fs, process, thread};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant, UNIX_EPOCH};
+    use vr_type::genetlink::resolve_family_id;
+    use vr_type::netlink::pcap::{read_pcap, Direction, PcapWriter, LINKTYPE_NETLINK};
+    use vr_type::genetlink::GenericNetlinkMessage;
+    use vr_type::genetlink::{CTRL_ATTR_FAMILY_NAME, CTRL_CMD_GETFAMILY, GENL_ID_CTRL};
+    use vr_type::genetlink::MessageHandleError;
+    use vr_type::netlink::*;
+
+    fn encode(ty: u16, flags: u16, payload: Vec<u8>) -> Vec<u8> {
+        let genl_msg = GenericNetlinkMessage::new(1, 2, payload);
+        let nl_msg = NetlinkMessage::new(ty, flags, genl_msg);
+        let mut buf = vec![0; NLMSG_ALIGN(nl_msg.len()) as usize];
+        let len = nl_msg.len() as usize;
+        nl_msg.serialize(&mut buf[..len]);
+        buf
+    }
+
+    #[test]
+    fn deserialize_multipart_datagram() {
+        let mut datagram = encode(0x20, NLM_F_MULTI, vec![1, 2, 3]);
+        datagram.extend(encode(0x20, NLM_F_MULTI, vec![4, 5, 6, 7, 8]));
+        datagram.extend(encode(NLMSG_DONE as u16, NLM_F_MULTI, vec![0, 0, 0, 0]));
+        let messages: Vec<_> = deserialize_messages(&datagram[..])
+            .map(|m| m.unwrap())
+            .collect();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].ty, 0x20);
+        assert_eq!(&messages[0].payload[4..], &[1, 2, 3]);
+        assert_eq!(&messages[1].payload[4..], &[4, 5, 6, 7, 8]);
+        assert_eq!(messages[2].ty, NLMSG_DONE as u16);
+    }
+
+    #[test]
+    fn deserialize_truncated_message() {
+        let datagram = encode(0x20, 0, vec![1, 2, 3, 4]);
+        let mut iter = deserialize_messages(&datagram[..10]);
+        assert_eq!(iter.next().unwrap().is_err(), true);
+        assert_eq!(iter.next().is_none(), true);
+
+        let mut iter = deserialize_messages(&datagram[..18]);
+        assert_eq!(iter.next().unwrap().is_err(), true);
+    }
+
+    #[test]
+    fn recv_padded_stream() {
+        // 16 + 4 + 5 bytes, padded to 28 on the stream
+        let mut stream = encode(0x20, NLM_F_MULTI, vec![1, 2, 3, 4, 5]);
+        stream.extend(encode(0x20, NLM_F_MULTI, vec![6]));
+        stream.extend(encode(NLMSG_DONE as u16, NLM_F_MULTI, vec![]));
+        let genl_msg = GenericNetlinkMessage::new(1, 2, vec![]);
+        let request = NetlinkMessage::new(0x20, NLM_F_REQUEST, genl_msg);
+        let mut stream = io::Cursor::new(stream);
+        let replies = request.recv_stream(&mut stream).unwrap();
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[0].payload.payload, vec![1, 2, 3, 4, 5]);
+        assert_eq!(replies[1].payload.payload, vec![6]);
+        assert_eq!(stream.position() as usize, stream.get_ref().len());
+    }
+
+    fn encode_attr<P: Serialize>(ty: u32, payload: P) -> Vec<u8> {
+        let attr = NetlinkAttr::new(ty as u16, payload);
+        let mut buf = vec![0; attr.len() as usize];
+        attr.serialize(&mut buf[..]);
+        buf
+    }
+
+    fn encode_nlmsgerr(error: i32, echo: &[u8]) -> Vec<u8> {
+        let mut buf = error.to_ne_bytes().to_vec();
+        buf.extend(&(NLMSG_HDRLEN + echo.len() as u32).to_ne_bytes());
+        buf.extend(&[0; 12]);
+        buf.extend(echo);
+        buf
+    }
+
+    #[test]
+    fn deserialize_plain_ack() {
+        let ack = NetlinkAck::deserialize(0, &encode_nlmsgerr(0, &[])).unwrap();
+        assert_eq!(ack.is_ack(), true);
+        assert_eq!(ack.offset, None);
+        assert_eq!(ack.message, None);
+    }
+
+    #[test]
+    fn deserialize_extended_ack() {
+        let msg = CString::new("missing attribute").unwrap();
+        let mut buf = encode_nlmsgerr(-libc::EINVAL, &[]);
+        buf.extend(encode_attr(NLMSGERR_ATTR_MSG, &msg));
+        buf.extend(encode_attr(NLMSGERR_ATTR_OFFS, 24u32));
+        let ack = NetlinkAck::deserialize(NLM_F_CAPPED | NLM_F_ACK_TLVS, &buf).unwrap();
+        assert_eq!(ack.errno, libc::EINVAL);
+        assert_eq!(ack.offset, Some(24));
+        assert_eq!(ack.message, Some("missing attribute".to_string()));
+    }
+
+    #[test]
+    fn deserialize_extended_ack_with_echo() {
+        let mut buf = encode_nlmsgerr(-1, &[0xff; 8]);
+        buf.extend(encode_attr(NLMSGERR_ATTR_OFFS, 20u32));
+        let ack = NetlinkAck::deserialize(NLM_F_ACK_TLVS, &buf).unwrap();
+        assert_eq!(ack.errno, 1);
+        assert_eq!(ack.offset, Some(20));
+        assert_eq!(ack.message, None);
+    }
+
+    #[test]
+    fn deserialize_short_error() {
+        assert_eq!(NetlinkAck::deserialize(0, &[0; 8]).is_err(), true);
+    }
+
+    #[test]
+    fn batch_round_trip() {
+        let mut socket = Socket::new(Protocol::Generic).unwrap();
+        let port_id = socket.bind_auto().unwrap().port_number();
+        let names = ["nlctrl", "no-such-family", "nlctrl"];
+        let names: Vec<_> = names.iter().map(|n| CString::new(*n).unwrap()).collect();
+        let attrs: Vec<_> = names
+            .iter()
+            .map(|name| [NetlinkAttr::new(CTRL_ATTR_FAMILY_NAME, name)])
+            .collect();
+        let requests: Vec<_> = attrs
+            .iter()
+            .enumerate()
+            .map(|(seq, attr)| {
+                let genl_msg =
+                    GenericNetlinkMessage::new(CTRL_CMD_GETFAMILY, 2, &attr[..]);
+                NetlinkMessage::new(GENL_ID_CTRL, NLM_F_REQUEST, genl_msg)
+                    .with_seq(seq as u32 + 1, port_id)
+            })
+            .collect();
+        send_batch_nl(&requests, &socket).unwrap();
+        let replies = recv_batch_nl(&requests, &socket).unwrap();
+        assert_eq!(replies.len(), 3);
+        assert_eq!(replies[0].as_ref().unwrap().len(), 1);
+        match &replies[1] {
+            Err(NetlinkError::Kernel(ack)) => assert_eq!(ack.errno, libc::ENOENT),
+            res => panic!("unexpected reply: {:?}", res),
+        }
+        assert_eq!(replies[2].as_ref().unwrap()[0].seq, 3);
+    }
+
+    #[test]
+    fn recv_timeout() {
+        let mut socket = Socket::new(Protocol::Generic).unwrap();
+        let port_id = socket.bind_auto().unwrap().port_number();
+        set_recv_timeout(&socket, Some(Duration::from_millis(50))).unwrap();
+        // Nothing has been sent, so no reply will ever come
+        let nl_msg = NetlinkMessage::new(GENL_ID_CTRL, NLM_F_REQUEST, vec![0u8; 4])
+            .with_seq(1, port_id);
+        let started = Instant::now();
+        let err = nl_msg.recv_nl(&socket).unwrap_err();
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        match err {
+            NetlinkError::Io(ref e) => assert_eq!(e.kind(), io::ErrorKind::WouldBlock),
+            ref err => panic!("unexpected error: {:?}", err),
+        }
+        match MessageHandleError::from(err) {
+            MessageHandleError::Timeout => (),
+            err => panic!("unexpected error: {:?}", err),
+        }
+        assert!(set_recv_timeout(&socket, Some(Duration::from_secs(0))).is_err());
+        set_recv_buffer_size(&socket, 1 << 20).unwrap();
+    }
+
+    #[test]
+    fn pcap_writer() {
+        let mut writer = PcapWriter::new(vec![]).unwrap();
+        let timestamp = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_000);
+        let datagram = encode(0x20, NLM_F_REQUEST, vec![1, 2, 3, 4]);
+        writer
+            .write_frame(timestamp, Direction::Outgoing, 16, &datagram[..])
+            .unwrap();
+        let pcap = writer.into_inner();
+        assert_eq!(pcap.len(), 24 + 16 + 16 + datagram.len());
+        assert_eq!(&pcap[20..24], &LINKTYPE_NETLINK.to_ne_bytes());
+        let record = &pcap[24..];
+        assert_eq!(&record[0..4], &1_600_000_000u32.to_ne_bytes());
+        assert_eq!(&record[4..8], &123_456u32.to_ne_bytes());
+        assert_eq!(&record[8..12], &(16 + datagram.len() as u32).to_ne_bytes());
+        // cooked header: PACKET_OUTGOING, ARPHRD_NETLINK, NETLINK_GENERIC
+        assert_eq!(&record[16..20], &[0, 4, 0x03, 0x38]);
+        assert_eq!(&record[30..32], &[0, 16]);
+        assert_eq!(&record[32..], &datagram[..]);
+    }
+
+    #[derive(Clone)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> { Ok(()) }
+    }
+
+    #[test]
+    fn capture_tap() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(vec![])));
+        start_capture_to(buffer.clone()).unwrap();
+        resolve_family_id("nlctrl").unwrap();
+        stop_capture().unwrap();
+        let pcap = buffer.0.lock().unwrap();
+        // The file header, then at least the request and its reply
+        let mut offset = 24;
+        let mut directions = vec![];
+        while offset < pcap.len() {
+            let len = &pcap[offset + 8..offset + 12];
+            let incl_len = u32::from_ne_bytes([len[0], len[1], len[2], len[3]]);
+            directions.push(pcap[offset + 17]);
+            offset += 16 + incl_len as usize;
+        }
+        assert_eq!(offset, pcap.len());
+        assert!(directions.contains(&4));
+        assert!(directions.contains(&0));
+    }
+
+    #[test]
+    fn pcap_reader() {
+        let mut writer = PcapWriter::new(vec![]).unwrap();
+        let timestamp = UNIX_EPOCH + Duration::new(1_600_000_000, 5_000);
+        let request = encode(0x20, NLM_F_REQUEST, vec![1, 2, 3, 4]);
+        let reply = encode(0x20, 0, vec![5, 6, 7, 8]);
+        writer
+            .write_frame(timestamp, Direction::Outgoing, 16, &request[..])
+            .unwrap();
+        writer
+            .write_frame(timestamp, Direction::Incoming, 16, &reply[..])
+            .unwrap();
+        let pcap = writer.into_inner();
+        let frames: Vec<_> = read_pcap(&pcap[..]).unwrap().map(|f| f.unwrap()).collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].timestamp, timestamp);
+        assert_eq!(frames[0].direction, Direction::Outgoing);
+        assert_eq!(frames[0].protocol, 16);
+        assert_eq!(frames[0].data, &request[..]);
+        assert_eq!(frames[1].direction, Direction::Incoming);
+        assert_eq!(frames[1].data, &reply[..]);
+
+        let mut frames = read_pcap(&pcap[..pcap.len() - 1]).unwrap();
+        assert_eq!(frames.next().unwrap().is_ok(), true);
+        assert_eq!(frames.next().unwrap().is_err(), true);
+        assert_eq!(frames.next().is_none(), true);
+        assert_eq!(read_pcap(&[0u8; 24]).is_err(), true);
+    }
+
+    #[test]
+    fn in_netns_test() {
+        // A thread parked in a fresh namespace stands in for a test topology
+        let (ready, tid) = mpsc::channel();
+        let (stop, stopped) = mpsc::channel::<()>();
+        let holder = thread::spawn(move || {
+            if unsafe { libc::unshare(libc::CLONE_NEWNET) } < 0 {
+                return ready.send(None).unwrap();
+            }
+            ready.send(Some(unsafe { libc::syscall(libc::SYS_gettid) })).unwrap();
+            let _ = stopped.recv();
+        });
+        let tid = match tid.recv().unwrap() {
+            Some(tid) => tid,
+            // Creating a namespace needs CAP_SYS_ADMIN, this test will skip.
+            None => return,
+        };
+        let path = format!("/proc/{}/task/{}/ns/net", process::id(), tid);
+        let netns = NetNs::Path(path.into());
+        let ns_ino = |path: &str| fs::metadata(path).unwrap().ino();
+        let own = ns_ino("/proc/thread-self/ns/net");
+        let joined = in_netns(&netns, move || ns_ino("/proc/thread-self/ns/net"));
+        let joined = joined.unwrap();
+        assert_eq!(joined, fs::metadata(netns.path()).unwrap().ino());
+        assert_ne!(joined, own);
+        assert_eq!(ns_ino("/proc/thread-self/ns/net"), own);
+        // The controller is reachable through a socket created in there
+        let socket = in_netns(&netns, || Socket::new(Protocol::Generic)).unwrap();
+        assert_eq!(socket.is_ok(), true);
+        stop.send(()).unwrap();
+        holder.join().unwrap();
+
+        let own_ns = NetNs::Pid(process::id());
+        let own_path = PathBuf::from(format!("/proc/{}/ns/net", process::id()));
+        assert_eq!(own_ns.path(), own_path);
+        let missing = NetNs::named("no-such-netns");
+        assert_eq!(in_netns(&missing, || ()).is_err(), true);
+    }
+
+    #[test]
+    fn typed_attrs_round_trip() {
+        const LEAF_POLICY: &[(u16, AttrKind)] = &[(1, AttrKind::U8), (2, AttrKind::Flag)];
+        const POLICY: &[(u16, AttrKind)] = &[
+            (1, AttrKind::U16),
+            (2, AttrKind::U32),
+            (3, AttrKind::U64),
+            (4, AttrKind::I32),
+            (5, AttrKind::String),
+            (6, AttrKind::Binary),
+            (7, AttrKind::Nested(LEAF_POLICY)),
+            (8, AttrKind::Array(LEAF_POLICY)),
+        ];
+        let attrs = vec![
+            Attr::u16(1, 0x1234),
+            Attr::u32(2, 0xdead_beef),
+            Attr::u64(3, u64::max_value()),
+            Attr::i32(4, -libc::ENOENT),
+            Attr::string(5, "vhost0"),
+            Attr::binary(6, vec![1, 2, 3]),
+            Attr::nested(7, vec![Attr::u8(1, 7), Attr::flag(2)]),
+            Attr::nested(8, vec![Attr::nested(1, vec![Attr::u8(1, 1)])]),
+            // Not in the policy, kept as a tree thanks to NLA_F_NESTED
+            Attr::nested(9, vec![Attr::binary(1, vec![0xff])]),
+        ];
+        let mut buf = vec![0; Serialize::len(&&attrs[..]) as usize];
+        (&attrs[..]).serialize(&mut buf[..]);
+        assert_eq!(buf.len() % 4, 0);
+        // nla_len counts the NUL terminator, the padding is zeroed
+        assert_eq!(u16::from_ne_bytes([buf[36], buf[37]]), 11);
+        assert_eq!(&buf[40..48], b"vhost0\0\0");
+        // NLA_F_NESTED is set on the wire
+        assert_eq!(u16::from_ne_bytes([buf[58], buf[59]]), 7 | NLA_F_NESTED);
+        let parsed = parse_attrs(&buf[..], POLICY).unwrap();
+        assert_eq!(parsed, attrs);
+        let nested = find_attr(&parsed, 7).unwrap();
+        assert_eq!(nested.get(1).and_then(Attr::as_u8), Some(7));
+        assert_eq!(nested.get(2).map(|attr| &attr.value), Some(&AttrValue::Flag));
+        assert_eq!(find_attr(&parsed, 5).and_then(Attr::as_str), Some("vhost0"));
+        assert_eq!(find_attr(&parsed, 4).and_then(Attr::as_u32), None);
+
+        // Without a policy everything is binary, but for NLA_F_NESTED
+        let parsed = parse_attrs(&buf[..], &[]).unwrap();
+        assert_eq!(parsed[0].as_bytes(), Some(&0x1234u16.to_ne_bytes()[..]));
+        assert_eq!(parsed[6].children().len(), 2);
+        // A value that does not fit its type is rejected
+        assert_eq!(parse_attrs(&buf[..], &[(1, AttrKind::U32)]).is_err(), true);
+    }
+}
@@ -1,2 +1,37 @@
 // Copyright 2020 Eishun Kondoh
 // SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_vr_flow {
+    use vr_type::vr_messages::vr_flow::{
+        FlowAction, FlowDropReason, FlowOp, FlowRequest, VR_FLOW_DR_UNKNOWN,
+    };
+
+    #[test]
+    fn unknown_enumerators() {
+        let mut fr: FlowRequest = FlowRequest::default();
+        fr.op = FlowOp::Unknown(7);
+        fr.action = FlowAction::Unknown(-1);
+        fr.drop_reason = FlowDropReason::Unknown(0x100);
+
+        let bytes = fr.write().unwrap();
+        let fr: FlowRequest = FlowRequest::read(bytes).unwrap();
+
+        assert_eq!(fr.op, FlowOp::Unknown(7));
+        assert_eq!(fr.action, FlowAction::Unknown(-1));
+        assert_eq!(fr.drop_reason, FlowDropReason::Unknown(0x100));
+        assert_eq!(u16::from(fr.drop_reason), 0x100);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(FlowOp::List.to_string(), "list");
+        assert_eq!(FlowAction::Nat.to_string(), "nat");
+        assert_eq!(FlowAction::Unknown(9).to_string(), "unknown(9)");
+        assert_eq!(FlowDropReason::FlowLimit.to_string(), "flow limit");
+        assert_eq!(FlowDropReason::default(), FlowDropReason::Unspecified);
+        assert_eq!(FlowDropReason::from(VR_FLOW_DR_UNKNOWN), FlowDropReason::Unspecified);
+        assert_eq!(u16::from(FlowDropReason::Unspecified), VR_FLOW_DR_UNKNOWN);
+        assert_eq!(FlowDropReason::Unspecified.to_string(), "unknown");
+    }
+}
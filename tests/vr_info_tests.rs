@@ -0,0 +1,79 @@
+// Copyright 2020 Eishun Kondoh
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod test_vr_info {
+    use vr_type::vr_messages::sandesh::SandeshOp;
+    use vr_type::vr_messages::vr_info::{InfoMsg, InfoRequest};
+
+    #[test]
+    fn empty_request() {
+        let vdu: InfoRequest = InfoRequest::default();
+        let bytes = vdu.write().unwrap();
+        let vdu: InfoRequest = InfoRequest::read(bytes).unwrap();
+        assert_eq!(vdu.op, SandeshOp::Add);
+        assert_eq!(vdu.rid, 0);
+        assert_eq!(vdu.index, 0);
+        assert_eq!(vdu.buff_table_id, 0);
+        assert_eq!(vdu.marker, 0);
+        assert_eq!(vdu.msginfo, InfoMsg::Bond);
+        assert_eq!(vdu.outbufsz, 0);
+        assert_eq!(vdu.inbuf, vec![]);
+        assert_eq!(vdu.proc_info, vec![]);
+    }
+
+    #[test]
+    fn complex_request() {
+        let mut vdu: InfoRequest = InfoRequest::dump(InfoMsg::Xstats);
+
+        vdu.rid = 1;
+        vdu.index = 2;
+        vdu.buff_table_id = 3;
+        vdu.marker = 4;
+        vdu.outbufsz = 5;
+        vdu.inbuf = vec![0x2d, 0x61];
+        vdu.proc_info = "mbuf pool\n\0".bytes().map(|c| c as i8).collect();
+
+        let bytes = vdu.write().unwrap();
+        let vdu: InfoRequest = InfoRequest::read(bytes).unwrap();
+
+        assert_eq!(vdu.op, SandeshOp::Dump);
+        assert_eq!(vdu.rid, 1);
+        assert_eq!(vdu.index, 2);
+        assert_eq!(vdu.buff_table_id, 3);
+        assert_eq!(vdu.marker, 4);
+        assert_eq!(vdu.msginfo, InfoMsg::Xstats);
+        assert_eq!(vdu.outbufsz, 5);
+        assert_eq!(vdu.inbuf, vec![0x2d, 0x61]);
+        assert_eq!(vdu.proc_info_text(), "mbuf pool\n");
+    }
+
+    #[test]
+    fn msginfo() {
+        assert_eq!(InfoMsg::from(2), InfoMsg::Mempool);
+        assert_eq!(i16::from(InfoMsg::App), 6);
+        assert_eq!(InfoMsg::from(42), InfoMsg::Unknown(42));
+        assert_eq!(i16::from(InfoMsg::Unknown(42)), 42);
+        assert_eq!(InfoMsg::Lacp.to_string(), "lacp");
+    }
+
+    #[test]
+    fn next_chunk() {
+        let request = InfoRequest::dump(InfoMsg::Lcore);
+        let mut reply = request.clone();
+        reply.marker = 1;
+        reply.buff_table_id = 7;
+        reply.proc_info = vec![0x61];
+
+        let next = request.next_chunk(&reply).unwrap();
+        assert_eq!(next.marker, 1);
+        assert_eq!(next.buff_table_id, 7);
+        assert_eq!(next.msginfo, InfoMsg::Lcore);
+
+        // The marker not moving forward, or no text, ends the dump
+        assert_eq!(next.next_chunk(&reply), None);
+        reply.marker = 2;
+        reply.proc_info = vec![];
+        assert_eq!(next.next_chunk(&reply), None);
+    }
+}
@@ -269,4 +269,19 @@ mod test_vr_interface {
         assert_eq!(ifreq.vlan_tag, 1);
         assert_eq!(ifreq.vlan_name, "test vlan".to_string());
     }
+
+    #[test]
+    fn unknown_enumerators() {
+        let mut ifreq: InterfaceRequest = InterfaceRequest::default();
+        ifreq.op = SandeshOp::Unknown(42);
+        ifreq._type = IfType::Unknown(99);
+
+        let bytes = ifreq.write().unwrap();
+        let ifreq: InterfaceRequest = InterfaceRequest::read(bytes).unwrap();
+
+        assert_eq!(ifreq.op, SandeshOp::Unknown(42));
+        assert_eq!(ifreq._type, IfType::Unknown(99));
+        assert_eq!(ifreq._type.to_string(), "unknown(99)");
+        assert_eq!(IfType::VirtualVlan.to_string(), "virtual-vlan");
+    }
 }
@@ -3,8 +3,28 @@
 
 #[cfg(test)]
 mod test_vr_messages {
+    use std::cell::RefCell;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use vr_type::genetlink::{send_sandesh_batch_with, MessageHandleError, Transport};
+    use vr_type::genetlink::{send_sandesh_msg_with, RoundTrip};
+    use vr_type::genetlink::{send_sandesh_batch_with_retry, send_sandesh_msg_with_retry};
+    use vr_type::genetlink::{clear_observer, set_observer, RetryPolicy};
     use vr_type::vr_messages::*;
 
+    // Records every request and answers with a canned sandesh reply.
+    struct FakeTransport {
+        requests: RefCell<Vec<Vec<u8>>>,
+        reply: Vec<u8>,
+    }
+
+    impl Transport for FakeTransport {
+        fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+            self.requests.borrow_mut().push(payload.to_vec());
+            Ok(vec![self.reply.clone()])
+        }
+    }
+
     #[test]
     fn bridge_table_data() {
         let mut expected = BridgeTableData::default();
@@ -149,6 +169,18 @@ mod test_vr_messages {
         );
     }
 
+    #[test]
+    fn info_req() {
+        let mut expected = InfoRequest::default();
+        expected.read_length = 71;
+        let req = Message::InfoRequest(InfoRequest::default());
+        let bytes = req.to_bytes().unwrap();
+        assert_eq!(
+            Message::InfoRequest(expected),
+            Message::from_bytes(bytes).unwrap()
+        );
+    }
+
     #[test]
     fn pkt_droplog() {
         let mut expected = PktDropLog::default();
@@ -257,6 +289,276 @@ mod test_vr_messages {
         );
     }
 
+    #[test]
+    fn send_with_transport() {
+        let vrouter_ops = Message::VrouterOps(VrouterOps::default());
+        let mut reply = Message::VrResponse(VrResponse::default()).to_bytes().unwrap();
+        reply.extend(vrouter_ops.to_bytes().unwrap());
+        let transport = FakeTransport {
+            requests: RefCell::new(vec![]),
+            reply: reply,
+        };
+        let mut request = VrouterOps::default();
+        request.op = SandeshOp::Get;
+        let request = Message::VrouterOps(request);
+        let replies = request.send_with(&transport).unwrap();
+        assert_eq!(*transport.requests.borrow(), vec![request.to_bytes().unwrap()]);
+        assert_eq!(
+            replies,
+            vec![Message::from_bytes(vrouter_ops.to_bytes().unwrap()).unwrap()]
+        );
+    }
+
+    #[test]
+    fn send_batch_with_transport() {
+        let vrouter_ops = Message::VrouterOps(VrouterOps::default());
+        let mut reply = Message::VrResponse(VrResponse::default()).to_bytes().unwrap();
+        reply.extend(vrouter_ops.to_bytes().unwrap());
+        let transport = FakeTransport {
+            requests: RefCell::new(vec![]),
+            reply: reply,
+        };
+        let requests = vec![
+            Message::VrouterOps(VrouterOps::default()),
+            Message::RouteRequest(RouteRequest::default()),
+        ];
+        let replies = send_sandesh_batch_with(&transport, &requests).unwrap();
+        assert_eq!(transport.requests.borrow().len(), 2);
+        assert_eq!(replies.len(), 2);
+        for reply in replies {
+            assert_eq!(reply.unwrap().len(), 1);
+        }
+    }
+
+    #[test]
+    fn send_with_transport_error() {
+        let mut resp = VrResponse::default();
+        resp.code = -libc::ENOENT;
+        let transport = FakeTransport {
+            requests: RefCell::new(vec![]),
+            reply: Message::VrResponse(resp).to_bytes().unwrap(),
+        };
+        let request = Message::VrouterOps(VrouterOps::default());
+        match request.send_with(&transport) {
+            Err(MessageHandleError::RequestError(OperationError::ENOENT)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn send_with_transport_invalid_object() {
+        let vrouter_ops = Message::VrouterOps(VrouterOps::default());
+        let mut reply = Message::VrResponse(VrResponse::default()).to_bytes().unwrap();
+        reply.extend(vrouter_ops.to_bytes().unwrap());
+        reply.extend_from_slice(b"\x00\x00\x00\x07unknown\x00");
+        let transport = FakeTransport {
+            requests: RefCell::new(vec![]),
+            reply: reply,
+        };
+        match vrouter_ops.send_with(&transport) {
+            Err(MessageHandleError::InvalidBuffer(_)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    // Answers each request with the next vr_response code of the script.
+    struct FlakyTransport {
+        codes: RefCell<Vec<i32>>,
+        requests: RefCell<usize>,
+    }
+
+    impl Transport for FlakyTransport {
+        fn request(&self, _payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+            *self.requests.borrow_mut() += 1;
+            let mut resp = VrResponse::default();
+            resp.code = self.codes.borrow_mut().remove(0);
+            Ok(vec![Message::VrResponse(resp).to_bytes().unwrap()])
+        }
+    }
+
+    fn flaky_transport(codes: Vec<i32>) -> FlakyTransport {
+        FlakyTransport {
+            codes: RefCell::new(codes),
+            requests: RefCell::new(0),
+        }
+    }
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn send_with_retry() {
+        let mut route = RouteRequest::default();
+        route.op = SandeshOp::Del;
+        let request = Message::RouteRequest(route);
+        let transport = flaky_transport(vec![-libc::EBUSY, -libc::ENOMEM, 0]);
+        let replies = send_sandesh_msg_with_retry(&transport, &request, &fast_retry());
+        assert_eq!(replies.unwrap(), vec![]);
+        assert_eq!(*transport.requests.borrow(), 3);
+
+        // Only the errors of the policy are retried
+        let transport = flaky_transport(vec![-libc::EINVAL, 0]);
+        match send_sandesh_msg_with_retry(&transport, &request, &fast_retry()) {
+            Err(MessageHandleError::RequestError(OperationError::EINVAL)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(*transport.requests.borrow(), 1);
+
+        // and no more than max_attempts times
+        let transport = flaky_transport(vec![-libc::EBUSY; 5]);
+        match send_sandesh_msg_with_retry(&transport, &request, &fast_retry()) {
+            Err(MessageHandleError::RequestError(OperationError::EBUSY)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(*transport.requests.borrow(), 5);
+    }
+
+    #[test]
+    fn retry_is_idempotency_aware() {
+        let mut policy = fast_retry();
+        policy.retryable.push(OperationError::EEXIST);
+        policy.retryable.push(OperationError::ENOENT);
+        let mut add = RouteRequest::default();
+        add.op = SandeshOp::Add;
+        let add = Message::RouteRequest(add);
+        let transport = flaky_transport(vec![-libc::EBUSY, -libc::EEXIST, 0]);
+        match send_sandesh_msg_with_retry(&transport, &add, &policy) {
+            Err(MessageHandleError::RequestError(OperationError::EEXIST)) => (),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        assert_eq!(*transport.requests.borrow(), 2);
+
+        let mut del = RouteRequest::default();
+        del.op = SandeshOp::Del;
+        let del = Message::RouteRequest(del);
+        let err = MessageHandleError::RequestError(OperationError::ENOENT);
+        assert_eq!(policy.should_retry(&del, &err, 1), false);
+        let err = MessageHandleError::RequestError(OperationError::EEXIST);
+        assert_eq!(policy.should_retry(&del, &err, 1), true);
+        assert_eq!(RetryPolicy::never().should_retry(&del, &err, 1), false);
+        assert_eq!(policy.should_retry(&del, &MessageHandleError::Timeout, 1), false);
+    }
+
+    #[test]
+    fn retry_backoff() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(50),
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(10));
+        assert_eq!(policy.backoff(2), Duration::from_millis(20));
+        assert_eq!(policy.backoff(3), Duration::from_millis(40));
+        assert_eq!(policy.backoff(4), Duration::from_millis(50));
+        assert_eq!(policy.backoff(64), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn send_batch_with_retry() {
+        let requests = vec![
+            Message::VrouterOps(VrouterOps::default()),
+            Message::RouteRequest(RouteRequest::default()),
+        ];
+        // The whole batch first, then the busy entry alone
+        let transport = flaky_transport(vec![0, -libc::EBUSY, 0]);
+        let replies = send_sandesh_batch_with_retry(&transport, &requests, &fast_retry());
+        let replies = replies.unwrap();
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies.iter().all(Result::is_ok), true);
+        assert_eq!(*transport.requests.borrow(), 3);
+    }
+
+    #[test]
+    fn observe_round_trips() {
+        let seen = Arc::new(Mutex::new(vec![]));
+        let recorder = seen.clone();
+        // Other tests run in parallel, only keep the requests of this one
+        set_observer(move |round_trip: &RoundTrip| {
+            if round_trip.message_type == MessageType::MplsRequest {
+                let error = round_trip.operation_error();
+                let mut seen = recorder.lock().unwrap();
+                seen.push((round_trip.op, round_trip.reply_count, error));
+            }
+        });
+        let mut mpls = MplsRequest::default();
+        mpls.op = SandeshOp::Del;
+        let request = Message::MplsRequest(mpls);
+        let transport = flaky_transport(vec![-libc::EBUSY, 0]);
+        send_sandesh_msg_with_retry(&transport, &request, &fast_retry()).unwrap();
+        let transport = flaky_transport(vec![0, -libc::ENOSPC]);
+        let batch = vec![request.clone(), request.clone()];
+        send_sandesh_batch_with(&transport, &batch).unwrap();
+        clear_observer();
+        send_sandesh_msg_with(&flaky_transport(vec![0]), &request).unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(
+            *seen,
+            vec![
+                (SandeshOp::Del, 0, Some(OperationError::EBUSY)),
+                (SandeshOp::Del, 0, None),
+                (SandeshOp::Del, 0, None),
+                (SandeshOp::Del, 0, Some(OperationError::ENOSPC)),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_buffer() {
+        assert!(Message::from_bytes(vec![]).is_err());
+        assert!(Message::from_bytes(vec![0, 0]).is_err());
+        assert!(Message::from_bytes(vec![0, 0, 0, 2, 0xff, 0xfe]).is_err());
+        assert!(Message::from_bytes(b"\x00\x00\x00\x07unknown".to_vec()).is_err());
+    }
+
+    #[test]
+    fn decode_borrowed_buffer() {
+        let mut vifr = InterfaceRequest::default();
+        vifr.name = "tap0".to_string();
+        let mut rtr = RouteRequest::default();
+        rtr.vrf_id = 1;
+        let messages = vec![
+            Message::InterfaceRequest(vifr),
+            Message::RouteRequest(rtr),
+            Message::VrfRequest(VrfRequest::default()),
+        ];
+        let mut buf = Vec::new();
+        for message in &messages {
+            buf.extend(message.to_bytes().unwrap());
+        }
+
+        let first = Message::from_slice(&buf).unwrap();
+        assert_eq!(first, Message::from_bytes(buf.clone()).unwrap());
+
+        let decoded: Vec<Message> = Message::iter_from_slice(&buf)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(3, decoded.len());
+        // The C codec leaves rtr_mac out of the route's read_length
+        let lens: Vec<usize> =
+            messages.iter().map(|m| m.to_bytes().unwrap().len()).collect();
+        assert_eq!(lens[0], decoded[0].read_length());
+        assert_eq!(lens[2], decoded[2].read_length());
+        assert_eq!(lens[1] - 6, decoded[1].read_length());
+        for (message, decoded) in messages.iter().zip(&decoded) {
+            assert_eq!(message.message_type(), decoded.message_type());
+        }
+        match &decoded[1] {
+            Message::RouteRequest(rtr) => assert_eq!(1, rtr.vrf_id),
+            message => panic!("decoded {:?}", message.message_type()),
+        }
+
+        // Decoding stops at the first object it cannot read
+        buf.extend_from_slice(b"\x00\x00\x00\x07unknown");
+        let mut iter = Message::iter_from_slice(&buf);
+        assert_eq!(3, iter.by_ref().take_while(Result::is_ok).count());
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn vrouter_ops_request() {
         use vr_type::genetlink::resolve_family_id;
@@ -278,4 +580,70 @@ mod test_vr_messages {
             }
         }
     }
+
+    // Answers each vr_info_req with the chunk its marker points at.
+    struct InfoTransport {
+        chunks: Vec<&'static [u8]>,
+        markers: RefCell<Vec<i16>>,
+    }
+
+    impl Transport for InfoTransport {
+        fn request(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+            let request = match Message::from_slice(payload).unwrap() {
+                Message::InfoRequest(vdu) => vdu,
+                m => panic!("unexpected request: {:?}", m),
+            };
+            self.markers.borrow_mut().push(request.marker);
+            let mut reply = request.clone();
+            reply.op = SandeshOp::Response;
+            reply.buff_table_id = 9;
+            if let Some(chunk) = self.chunks.get(request.marker as usize) {
+                reply.marker = request.marker + 1;
+                reply.proc_info = chunk.iter().map(|&c| c as i8).collect();
+            }
+            let mut buf = Message::VrResponse(VrResponse::default()).to_bytes().unwrap();
+            buf.extend(Message::InfoRequest(reply).to_bytes().unwrap());
+            Ok(vec![buf])
+        }
+    }
+
+    #[test]
+    fn info_req_continuation() {
+        let transport = InfoTransport {
+            chunks: vec![b"lcore 0: ", b"vif0/1\n\xc3", b"\xa9"],
+            markers: RefCell::new(vec![]),
+        };
+        let text = InfoRequest::dump(InfoMsg::Lcore).collect_with(&transport);
+        assert_eq!(text.unwrap(), "lcore 0: vif0/1\n\u{e9}");
+        assert_eq!(*transport.markers.borrow(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn info_req_split_character() {
+        let transport = InfoTransport {
+            chunks: vec![b"caf\xc3", b"\xa9\n"],
+            markers: RefCell::new(vec![]),
+        };
+        let text = InfoRequest::dump(InfoMsg::Lcore).collect_with(&transport);
+        assert_eq!(text.unwrap(), "caf\u{e9}\n");
+        assert_eq!(*transport.markers.borrow(), vec![0, 1, 2]);
+    }
+
+    // Answers with a bare vr_response, as a vrouter failing the request does.
+    struct ResponseOnlyTransport;
+
+    impl Transport for ResponseOnlyTransport {
+        fn request(&self, _: &[u8]) -> Result<Vec<Vec<u8>>, MessageHandleError> {
+            Ok(vec![Message::VrResponse(VrResponse::default()).to_bytes().unwrap()])
+        }
+    }
+
+    #[test]
+    fn info_req_without_reply() {
+        let text = InfoRequest::dump(InfoMsg::Lcore).collect_with(&ResponseOnlyTransport);
+        match text {
+            Err(MessageHandleError::MissingReply(MessageType::InfoRequest)) => (),
+            text => panic!("collected {:?}", text),
+        }
+    }
 }
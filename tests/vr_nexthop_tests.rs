@@ -108,4 +108,18 @@ mod test_vr_nexthop {
         assert_eq!(nhreq.rw_dst_mac, MacAddress::broadcast());
         assert_eq!(nhreq.transport_label, 1);
     }
+
+    #[test]
+    fn unknown_type() {
+        let mut nhreq: NexthopRequest = NexthopRequest::default();
+        nhreq._type = NhType::Unknown(20);
+
+        let bytes = nhreq.write().unwrap();
+        let nhreq: NexthopRequest = NexthopRequest::read(bytes).unwrap();
+
+        assert_eq!(nhreq._type, NhType::Unknown(20));
+        assert_eq!(i8::from(nhreq._type), 20);
+        assert_eq!(NhType::from(3), NhType::Tunnel);
+        assert_eq!(NhType::L2Rcv.to_string(), "l2-receive");
+    }
 }
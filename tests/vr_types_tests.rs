@@ -9,7 +9,7 @@ mod test_encode_types {
     fn vr_nexthop_req() {
         let req = vr_nexthop_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_nexthop_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_nexthop_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(214, res.len())
     }
 
@@ -17,7 +17,7 @@ mod test_encode_types {
     fn vr_interface_req() {
         let req = vr_interface_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_interface_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_interface_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(724, res.len())
     }
 
@@ -25,7 +25,7 @@ mod test_encode_types {
     fn vr_vxlan_req() {
         let req = vr_vxlan_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_vxlan_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_vxlan_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(43, res.len())
     }
 
@@ -33,7 +33,7 @@ mod test_encode_types {
     fn vr_route_req() {
         let req = vr_route_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_route_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_route_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(114, res.len())
     }
 
@@ -41,7 +41,7 @@ mod test_encode_types {
     fn vr_mpls_req() {
         let req = vr_mpls_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_mpls_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_mpls_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(49, res.len())
     }
 
@@ -49,7 +49,7 @@ mod test_encode_types {
     fn vr_mirror_req() {
         let req = vr_mirror_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_mirror_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_mirror_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(75, res.len())
     }
 
@@ -57,7 +57,7 @@ mod test_encode_types {
     fn vr_vrf_req() {
         let req = vr_vrf_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_vrf_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_vrf_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(62, res.len())
     }
 
@@ -65,7 +65,7 @@ mod test_encode_types {
     fn vr_flow_req() {
         let req = vr_flow_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_flow_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_flow_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(272, res.len())
     }
 
@@ -73,7 +73,7 @@ mod test_encode_types {
     fn vr_vrf_assign_req() {
         let req = vr_vrf_assign_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_vrf_assign_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_vrf_assign_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(63, res.len())
     }
 
@@ -81,7 +81,7 @@ mod test_encode_types {
     fn vr_vrf_stats_req() {
         let req = vr_vrf_stats_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_vrf_stats_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_vrf_stats_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(352, res.len())
     }
 
@@ -89,7 +89,7 @@ mod test_encode_types {
     fn vr_response() {
         let req = vr_response::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_response", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_response", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(30, res.len())
     }
 
@@ -97,7 +97,7 @@ mod test_encode_types {
     fn vr_mem_stats_req() {
         let req = vr_mem_stats_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_mem_stats_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_mem_stats_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(803, res.len())
     }
 
@@ -107,7 +107,7 @@ mod test_encode_types {
         let res = req.write().unwrap();
         assert_eq!(
             "vr_pkt_drop_log_req",
-            sandesh_info_t::sname_from_bytes(&res)
+            sandesh_info_t::sname_from_bytes(&res).unwrap()
         );
         assert_eq!(74, res.len())
     }
@@ -116,7 +116,7 @@ mod test_encode_types {
     fn vr_drop_stats_req() {
         let req = vr_drop_stats_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_drop_stats_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_drop_stats_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(626, res.len())
     }
 
@@ -124,7 +124,7 @@ mod test_encode_types {
     fn vr_qos_map_req() {
         let req = vr_qos_map_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_qos_map_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_qos_map_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(89, res.len())
     }
 
@@ -132,7 +132,7 @@ mod test_encode_types {
     fn vr_fc_map_req() {
         let req = vr_fc_map_req::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_fc_map_req", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_fc_map_req", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(75, res.len())
     }
 
@@ -140,7 +140,7 @@ mod test_encode_types {
     fn vr_flow_response() {
         let req = vr_flow_response::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_flow_response", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_flow_response", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(70, res.len())
     }
 
@@ -148,7 +148,7 @@ mod test_encode_types {
     fn vr_flow_table_data() {
         let req = vr_flow_table_data::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_flow_table_data", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_flow_table_data", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(163, res.len())
     }
 
@@ -158,7 +158,7 @@ mod test_encode_types {
         let res = req.write().unwrap();
         assert_eq!(
             "vr_bridge_table_data",
-            sandesh_info_t::sname_from_bytes(&res)
+            sandesh_info_t::sname_from_bytes(&res).unwrap()
         );
         assert_eq!(56, res.len())
     }
@@ -167,7 +167,7 @@ mod test_encode_types {
     fn vr_hugepage_config() {
         let req = vr_hugepage_config::new();
         let res = req.write().unwrap();
-        assert_eq!("vr_hugepage_config", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vr_hugepage_config", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(77, res.len())
     }
 
@@ -175,7 +175,7 @@ mod test_encode_types {
     fn vrouter_ops() {
         let req = vrouter_ops::new();
         let res = req.write().unwrap();
-        assert_eq!("vrouter_ops", sandesh_info_t::sname_from_bytes(&res));
+        assert_eq!("vrouter_ops", sandesh_info_t::sname_from_bytes(&res).unwrap());
         assert_eq!(328, res.len())
     }
 }